@@ -0,0 +1,159 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::process;
+
+const USAGE: &str = "usage: aoc <day> [--part 1|2] [--input path]";
+
+struct Args
+{
+	day: u32,
+	part: Option< u32 >,
+	input: Option< String >,
+}
+
+fn parse_args() -> Result< Args, String >
+{
+	let mut args = env::args().skip( 1 );
+
+	let mut day: Option< u32 > = None;
+	let mut part: Option< u32 > = None;
+	let mut input: Option< String > = None;
+
+	while let Some( arg ) = args.next()
+	{
+		match arg.as_str()
+		{
+			"--part" =>
+			{
+				let value = args.next().ok_or( "--part needs a value" )?;
+				part = match value.as_str()
+				{
+					"1" => Some( 1 ),
+					"2" => Some( 2 ),
+					_ => return Err( format!( "invalid part {}", value ) ),
+				};
+			},
+			"--input" =>
+			{
+				input = Some( args.next().ok_or( "--input needs a path" )? );
+			},
+			_ =>
+			{
+				if day.is_some()
+				{
+					return Err( format!( "unexpected argument {}", arg ) );
+				}
+				day = Some( arg.parse::<u32>().map_err( |_| format!( "invalid day {}", arg ) )? );
+			},
+		}
+	}
+
+	return match day
+	{
+		Some( day ) => Ok( Args { day, part, input } ),
+		None => Err( "missing day".to_string() ),
+	};
+}
+
+// Every part is solved, so None means this input has no answer, like a
+// maze with no way through
+fn report< T: fmt::Display >( part: u32, answer: Option< T > )
+{
+	match answer
+	{
+		Some( answer ) => println!( "Part {}: {}", part, answer ),
+		None => println!( "Part {}: no answer", part ),
+	}
+}
+
+macro_rules! solve
+{
+	( $day: ident, $input: expr, $part: expr ) =>
+	{
+		{
+			let parsed = match $day::parse( $input )
+			{
+				Ok( parsed ) => parsed,
+				Err( e ) =>
+				{
+					eprintln!( "invalid input: {}", e );
+					process::exit( 1 );
+				}
+			};
+
+			if $part != Some( 2 )
+			{
+				report( 1, $day::part1( &parsed ) );
+			}
+			if $part != Some( 1 )
+			{
+				report( 2, $day::part2( &parsed ) );
+			}
+		}
+	}
+}
+
+fn main()
+{
+	let args = match parse_args()
+	{
+		Ok( args ) => args,
+		Err( e ) =>
+		{
+			eprintln!( "{}\n{}", e, USAGE );
+			process::exit( 2 );
+		}
+	};
+
+	let input = match &args.input
+	{
+		Some( path ) => fs::read_to_string( path ),
+		None => io::read_to_string( io::stdin() ),
+	};
+	let input = match input
+	{
+		Ok( input ) => input,
+		Err( e ) =>
+		{
+			eprintln!( "failed to read input: {}", e );
+			process::exit( 1 );
+		}
+	};
+	let input = input.as_str();
+
+	match args.day
+	{
+		1 => solve!( aoc1, input, args.part ),
+		2 => solve!( aoc2, input, args.part ),
+		3 => solve!( aoc3, input, args.part ),
+		4 => solve!( aoc4, input, args.part ),
+		5 => solve!( aoc5, input, args.part ),
+		6 => solve!( aoc6, input, args.part ),
+		7 => solve!( aoc7, input, args.part ),
+		8 => solve!( aoc8, input, args.part ),
+		9 => solve!( aoc9, input, args.part ),
+		10 => solve!( aoc10, input, args.part ),
+		11 => solve!( aoc11, input, args.part ),
+		12 => solve!( aoc12, input, args.part ),
+		13 => solve!( aoc13, input, args.part ),
+		14 => solve!( aoc14, input, args.part ),
+		15 => solve!( aoc15, input, args.part ),
+		16 => solve!( aoc16, input, args.part ),
+		17 => solve!( aoc17, input, args.part ),
+		18 => solve!( aoc18, input, args.part ),
+		19 => solve!( aoc19, input, args.part ),
+		20 => solve!( aoc20, input, args.part ),
+		21 => solve!( aoc21, input, args.part ),
+		22 => solve!( aoc22, input, args.part ),
+		23 => solve!( aoc23, input, args.part ),
+		24 => solve!( aoc24, input, args.part ),
+		25 => solve!( aoc25, input, args.part ),
+		_ =>
+		{
+			eprintln!( "no solver for day {}", args.day );
+			process::exit( 2 );
+		}
+	}
+}
@@ -0,0 +1,54 @@
+// Day 4's section assignments, analyzed in one pass.
+
+use aoc_common::Interval;
+
+fn sample() -> String
+{
+	return std::fs::read_to_string( concat!( env!( "CARGO_MANIFEST_DIR" ), "/../aoc4/data.txt" ) ).unwrap();
+}
+
+#[test]
+fn analysis()
+{
+	let analysis = aoc4::analyze( &aoc4::parse( &sample() ).unwrap() );
+	assert_eq!( analysis.contained, 2 );
+	assert_eq!( analysis.overlapping, 4 );
+	assert_eq!( analysis.overlap_sections, 1 + 5 + 1 + 3 );
+
+	assert_eq!( analysis.coverage.first(), Some( &( Interval::new( 2, 2 ), 4 ) ) );
+	assert_eq!( analysis.coverage.last(), Some( &( Interval::new( 9, 9 ), 1 ) ) );
+	assert_eq!( analysis.busiest(), ( vec![ Interval::new( 6, 6 ) ], 8 ) );
+}
+
+#[test]
+fn empty()
+{
+	let analysis = aoc4::analyze( &[] );
+	assert_eq!( analysis.overlap_sections, 0 );
+	assert_eq!( analysis.busiest(), ( vec![], 0 ) );
+}
+
+#[test]
+fn groups()
+{
+	let groups = aoc4::parse( "2-4,6-8\n1-5,3-9,2-4,8-8\n5-5,5-5,1-3\n7-9\n" ).unwrap();
+	assert_eq!( groups[ 1 ].elves.len(), 4 );
+	assert_eq!( groups[ 3 ].elves, vec![ Interval::new( 7, 9 ) ] );
+
+	let report = aoc4::group_report( &groups[ 0 ] );
+	assert_eq!( report.redundant, Vec::< usize >::new() );
+	assert!( report.shared.is_empty() );
+
+	// 2-4 is inside 1-5 and 8 is inside 3-9, but 1-5 and 3-9 each have
+	// sections nobody else cleans
+	let report = aoc4::group_report( &groups[ 1 ] );
+	assert_eq!( report.redundant, vec![ 2, 3 ] );
+	assert_eq!( report.shared.intervals(), &[ Interval::new( 2, 5 ), Interval::new( 8, 8 ) ] );
+
+	let report = aoc4::group_report( &groups[ 2 ] );
+	assert_eq!( report.redundant, vec![ 0, 1 ] );
+	assert_eq!( report.shared.intervals(), &[ Interval::new( 5, 5 ) ] );
+
+	let analysis = aoc4::analyze( &groups );
+	assert_eq!( ( analysis.contained, analysis.overlapping, analysis.overlap_sections ), ( 2, 2, 5 + 1 ) );
+}
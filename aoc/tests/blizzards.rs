@@ -0,0 +1,11 @@
+// Day 24's valley, for shapes the sample doesn't cover.
+
+#[test]
+fn tall_narrow_valley()
+{
+	// the blizzard going left wraps round the two columns many times
+	// while the party walks down the five rows
+	let weather = aoc24::parse( "#.##\n#<.#\n#..#\n#..#\n#..#\n#..#\n##.#\n" ).unwrap();
+	assert_eq!( aoc24::part1( &weather ), Some( 7 ) );
+	assert_eq!( aoc24::part2( &weather ), Some( 21 ) );
+}
@@ -0,0 +1,67 @@
+// Day 1's per-elf report and top-N rankings.
+
+use aoc1::Elf;
+
+fn elves( input: &str ) -> Vec< Elf >
+{
+	return aoc1::parse( input ).unwrap();
+}
+
+#[test]
+fn last_elf_without_blank_line()
+{
+	let with = elves( "1000\n2000\n\n4000\n\n" );
+	let without = elves( "1000\n2000\n\n4000" );
+	assert_eq!( with, without );
+	assert_eq!( without, vec![
+		Elf { index: 1, items: 2, total: 3000 },
+		Elf { index: 2, items: 1, total: 4000 },
+	] );
+}
+
+#[test]
+fn top_n()
+{
+	let list = elves( "100\n\n300\n\n200\n\n300\n" );
+
+	let ranked: Vec< usize > = aoc1::top( &list, 3 ).iter().map( | elf | elf.index ).collect();
+	assert_eq!( ranked, vec![ 2, 4, 3 ] );
+	assert_eq!( aoc1::top( &list, 10 ).len(), 4 );
+
+	assert_eq!( aoc1::top_total( &list, 1 ), Some( 300 ) );
+	assert_eq!( aoc1::top_total( &list, 3 ), Some( 800 ) );
+	assert_eq!( aoc1::top_total( &list, 5 ), None );
+}
+
+#[test]
+fn csv()
+{
+	let list = elves( "1000\n2000\n\n4000\n" );
+	assert_eq!( aoc1::to_csv( &list ), "elf,items,total\n1,2,3000\n2,1,4000\n" );
+}
+
+#[test]
+fn streaming_matches_sorting()
+{
+	// lots of ties, so the order they're broken in matters
+	let mut input = String::new();
+	for i in 0..500
+	{
+		input.push_str( &format!( "{}\n{}\n\n", ( i * 7919 ) % 101, i % 3 ) );
+	}
+	let list = elves( &input );
+
+	for k in [ 0, 1, 3, 10, 499, 500, 600 ]
+	{
+		let sorted: Vec< Elf > = aoc1::top( &list, k ).into_iter().cloned().collect();
+		let streamed = aoc1::top_streaming( input.lines().map( | line | line.to_string() ), k ).unwrap();
+		assert_eq!( streamed, sorted, "top {}", k );
+	}
+}
+
+#[test]
+fn streaming_errors()
+{
+	let result = aoc1::top_streaming( "100\n\nlots\n".lines(), 3 );
+	assert_eq!( result, Err( aoc_common::ParseError::new( 3, 1, "a calorie count" ) ) );
+}
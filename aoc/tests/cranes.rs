@@ -0,0 +1,190 @@
+// Day 5's stack drawings, and its crane models including ones the puzzle
+// doesn't have.
+
+use aoc5::Crane;
+use aoc5::Move;
+
+fn sample() -> aoc5::Crates
+{
+	let input = std::fs::read_to_string( concat!( env!( "CARGO_MANIFEST_DIR" ), "/../aoc5/fakedata.txt" ) ).unwrap();
+	return aoc5::parse( &input ).unwrap();
+}
+
+fn names( list: &str ) -> Vec< String >
+{
+	return list.split( ' ' ).map( | name | name.to_string() ).collect();
+}
+
+fn tops( crane: &dyn Crane ) -> String
+{
+	return aoc5::top_crates( &aoc5::run( &sample(), crane ) );
+}
+
+#[test]
+fn limited_crane()
+{
+	assert_eq!( tops( &aoc5::LimitedCrane::new( 1 ) ), tops( &aoc5::CrateMover9000 ) );
+	assert_eq!( tops( &aoc5::LimitedCrane::new( 3 ) ), tops( &aoc5::CrateMover9001 ) );
+	assert_eq!( tops( &aoc5::LimitedCrane::new( 2 ) ), "MCZ" );
+	assert_eq!( aoc5::LimitedCrane::new( 0 ).max_lift(), 1 );
+}
+
+// Always moves one crate, whatever it's asked for, and nothing if the
+// stack is already empty
+struct Lazy;
+
+impl Crane for Lazy
+{
+	fn name( self: &Self ) -> String
+	{
+		return "lazy".to_string();
+	}
+
+	fn apply( self: &Self, stacks: &mut [ Vec< String > ], m: &Move )
+	{
+		if let Some( c ) = stacks[ m.from ].pop()
+		{
+			stacks[ m.to ].push( c );
+		}
+	}
+}
+
+#[test]
+fn custom_crane()
+{
+	// D to 1 and straight on to 3, then C to 1 and back to 2
+	let stacks = aoc5::run( &sample(), &Lazy );
+	assert_eq!( stacks, vec![ names( "Z N" ), names( "M C" ), names( "P D" ) ] );
+	assert_eq!( aoc5::top_crates( &stacks ), "NCD" );
+}
+
+#[test]
+fn trimmed_lines()
+{
+	let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\n";
+	let crates = aoc5::parse( input ).unwrap();
+	assert_eq!( crates.stacks, sample().stacks );
+	assert_eq!( crates.labels, vec![ 1, 2, 3 ] );
+}
+
+#[test]
+fn wide_drawing()
+{
+	// stack numbers past 9, crates with long names, and a stack with nothing
+	// in it
+	let input = concat!(
+		"      [bolts]\n",
+		"[nut] [gears] [axle]\n",
+		"  1      2      10      11\n",
+		"\n",
+		"move 2 from 2 to 11\n",
+		"move 1 from 10 to 1\n",
+	);
+	let crates = aoc5::parse( input ).unwrap();
+	assert_eq!( crates.labels, vec![ 1, 2, 10, 11 ] );
+	assert_eq!( crates.stacks, vec![ names( "nut" ), names( "gears bolts" ), names( "axle" ), vec![] ] );
+
+	let stacks = aoc5::run( &crates, &aoc5::CrateMover9001 );
+	assert_eq!( stacks, vec![ names( "nut axle" ), vec![], vec![], names( "gears bolts" ) ] );
+	assert_eq!( aoc5::top_crates( &stacks ), "axle  bolts" );
+}
+
+#[test]
+fn render_matches_input()
+{
+	let input = std::fs::read_to_string( concat!( env!( "CARGO_MANIFEST_DIR" ), "/../aoc5/fakedata.txt" ) ).unwrap();
+	let drawing = input.split( "\n\n" ).next().unwrap();
+	let crates = sample();
+	assert_eq!( aoc5::render( &crates.labels, &crates.stacks ), format!( "{}\n", drawing ) );
+}
+
+#[test]
+fn render_wide()
+{
+	let labels = vec![ 1, 12 ];
+	let stacks = vec![ names( "nut bolts" ), names( "A" ) ];
+	let drawing = aoc5::render( &labels, &stacks );
+	assert_eq!( drawing, concat!(
+		"[bolts]    \n",
+		" [nut]  [A]\n",
+		"   1    12 \n",
+	) );
+
+	let crates = aoc5::parse( &format!( "{}\nmove 1 from 12 to 1\n", drawing ) ).unwrap();
+	assert_eq!( crates.labels, labels );
+	assert_eq!( crates.stacks, stacks );
+}
+
+#[test]
+fn journal()
+{
+	let crates = sample();
+	let crane = aoc5::CrateMover9001;
+	let mut journal = aoc5::Journal::new( &crates, &crane );
+	let start = journal.render();
+
+	let mut frames = vec![ start.clone() ];
+	while journal.redo()
+	{
+		frames.push( journal.render() );
+	}
+	assert_eq!( journal.position(), journal.len() );
+	assert_eq!( journal.stacks(), aoc5::run( &crates, &crane ).as_slice() );
+
+	// stepping back goes through the same frames in reverse
+	for k in ( 0..frames.len() - 1 ).rev()
+	{
+		assert!( journal.undo() );
+		assert_eq!( journal.render(), frames[ k ], "after undoing to {}", k );
+	}
+	assert!( !journal.undo() );
+
+	journal.jump_to( 3 );
+	assert_eq!( journal.render(), frames[ 3 ] );
+	journal.jump_to( 1 );
+	assert_eq!( journal.render(), frames[ 1 ] );
+	journal.jump_to( 100 );
+	assert_eq!( journal.position(), journal.len() );
+	assert_eq!( aoc5::describe_move( &crates, &crates.moves[ 0 ] ), "move 1 from 2 to 1" );
+}
+
+// Makes each move properly, then turns every stack upside down, including
+// ones the move didn't name
+struct Juggler;
+
+impl Crane for Juggler
+{
+	fn name( self: &Self ) -> String
+	{
+		return "juggler".to_string();
+	}
+
+	fn apply( self: &Self, stacks: &mut [ Vec< String > ], m: &Move )
+	{
+		aoc5::CrateMover9000.apply( stacks, m );
+		for stack in stacks.iter_mut()
+		{
+			stack.reverse();
+		}
+	}
+}
+
+#[test]
+fn journal_undoes_every_stack_changed()
+{
+	let crates = sample();
+	let mut journal = aoc5::Journal::new( &crates, &Juggler );
+
+	let mut frames = vec![ journal.render() ];
+	while journal.redo()
+	{
+		frames.push( journal.render() );
+	}
+	assert_eq!( journal.stacks(), aoc5::run( &crates, &Juggler ).as_slice() );
+
+	for k in ( 0..frames.len() - 1 ).rev()
+	{
+		assert!( journal.undo() );
+		assert_eq!( journal.render(), frames[ k ], "after undoing to {}", k );
+	}
+}
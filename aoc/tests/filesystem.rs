@@ -0,0 +1,87 @@
+// Day 7's directory tree, built from a terminal session.
+
+use aoc7::FileSystem;
+use aoc7::Kind;
+
+fn sample() -> FileSystem
+{
+	let input = std::fs::read_to_string( concat!( env!( "CARGO_MANIFEST_DIR" ), "/../aoc7/fakedata.txt" ) ).unwrap();
+	return aoc7::parse( &input ).unwrap();
+}
+
+fn sizes< 'a >( dirs: impl Iterator< Item = &'a aoc7::Entry > ) -> Vec< ( String, usize ) >
+{
+	return dirs.map( | dir | ( dir.name.clone(), dir.size ) ).collect();
+}
+
+#[test]
+fn directory_sizes()
+{
+	let fs = sample();
+	assert_eq!( fs.total_size(), 48381165 );
+	assert_eq!( sizes( fs.dirs() ), vec![
+		( "/".to_string(), 48381165 ),
+		( "a".to_string(), 94853 ),
+		( "d".to_string(), 24933642 ),
+		( "e".to_string(), 584 ),
+	] );
+	assert_eq!( sizes( fs.dirs_at_most( 100000 ) ), vec![ ( "a".to_string(), 94853 ), ( "e".to_string(), 584 ) ] );
+	assert_eq!( fs.smallest_dir_at_least( 1000 ), Some( 94853 ) );
+	assert_eq!( fs.smallest_dir_at_least( 50000000 ), None );
+
+	let e = fs.child( fs.child( aoc7::ROOT, "a" ).unwrap(), "e" ).unwrap();
+	assert_eq!( fs.path( e ), "/a/e/" );
+	assert_eq!( fs.path( fs.child( e, "i" ).unwrap() ), "/a/e/i" );
+}
+
+#[test]
+fn empty_files_and_directories()
+{
+	let fs = aoc7::parse( "$ ls\n0 empty\ndir nothing\n$ cd nothing\n$ ls\n" ).unwrap();
+	let empty = fs.child( aoc7::ROOT, "empty" ).unwrap();
+	assert_eq!( fs.entries[ empty ].kind, Kind::File );
+	assert_eq!( sizes( fs.dirs() ), vec![ ( "/".to_string(), 0 ), ( "nothing".to_string(), 0 ) ] );
+	assert_eq!( aoc7::dump( &fs ), " - / (0)\n     - empty (0)\n    - nothing/ (0)\n" );
+
+	let result = aoc7::parse( "$ ls\n0 empty\n$ cd empty\n" );
+	assert_eq!( result, Err( aoc_common::ParseError::new( 3, 6, "a directory listed by ls" ) ) );
+}
+
+#[test]
+fn listed_twice()
+{
+	let fs = aoc7::parse( "$ ls\n10 a\ndir b\n$ cd b\n$ ls\n5 c\n$ cd ..\n$ ls\n10 a\ndir b\n" ).unwrap();
+	assert_eq!( fs.entries.len(), 4 );
+	assert_eq!( fs.total_size(), 15 );
+}
+
+#[test]
+fn deep_tree()
+{
+	// every directory holds a file and the next directory down
+	let mut input = String::new();
+	for _ in 0..5000
+	{
+		input.push_str( "$ ls\n1 f\ndir d\n$ cd d\n" );
+	}
+	let fs = aoc7::parse( &input ).unwrap();
+	assert_eq!( fs.total_size(), 5000 );
+	assert_eq!( fs.dirs_at_most( 100 ).count(), 101 );
+	assert_eq!( fs.smallest_dir_at_least( 2500 ), Some( 2500 ) );
+	assert_eq!( aoc7::dump( &fs ).lines().count(), 10001 );
+}
+
+#[test]
+fn wide_directory()
+{
+	// one directory with a great many files, listed twice
+	let mut listing = String::from( "$ ls\n" );
+	for i in 0..50000
+	{
+		listing.push_str( &format!( "{} f{}\n", i % 7, i ) );
+	}
+	let fs = aoc7::parse( &format!( "{}{}", listing, listing ) ).unwrap();
+	assert_eq!( fs.entries.len(), 50001 );
+	assert_eq!( fs.child( aoc7::ROOT, "f49999" ), Some( 50000 ) );
+	assert_eq!( fs.total_size(), ( 0..50000 ).map( | i | i % 7 ).sum::< usize >() );
+}
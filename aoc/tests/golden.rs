@@ -0,0 +1,125 @@
+// Runs every day's solver over the sample inputs that ship next to it and
+// checks the answers against the ones recorded here.
+
+use std::fs;
+
+fn sample( path: &str ) -> String
+{
+	let full_path = format!( "{}/../{}", env!( "CARGO_MANIFEST_DIR" ), path );
+	return fs::read_to_string( &full_path ).unwrap();
+}
+
+macro_rules! golden
+{
+	( $( #[$attr: meta] )* $name: ident, $day: ident :: $part: ident, $file: expr, $expected: expr ) =>
+	{
+		#[test]
+		$( #[$attr] )*
+		fn $name()
+		{
+			let parsed = $day::parse( &sample( $file ) ).unwrap();
+			assert_eq!( $day::$part( &parsed ), Some( $expected ) );
+		}
+	};
+
+	// for days whose sample asks a different question from the real
+	// puzzle, calling the solver with the sample's parameters
+	( $( #[$attr: meta] )* $name: ident, $day: ident, | $parsed: ident | $solve: expr, $file: expr, $expected: expr ) =>
+	{
+		#[test]
+		$( #[$attr] )*
+		fn $name()
+		{
+			let $parsed = $day::parse( &sample( $file ) ).unwrap();
+			assert_eq!( $solve, Some( $expected ) );
+		}
+	};
+}
+
+golden!( day1_part1, aoc1::part1, "aoc1/data.txt", 24000 );
+golden!( day1_part2, aoc1::part2, "aoc1/data.txt", 45000 );
+
+golden!( day2_part1, aoc2::part1, "aoc2/fakedata.txt", 15 );
+golden!( day2_part2, aoc2::part2, "aoc2/fakedata.txt", 12 );
+
+golden!( day3_part1, aoc3::part1, "aoc3/fakedata.txt", 157 );
+golden!( day3_part2, aoc3::part2, "aoc3/fakedata.txt", 70 );
+
+golden!( day4_part1, aoc4::part1, "aoc4/data.txt", 2 );
+golden!( day4_part2, aoc4::part2, "aoc4/data.txt", 4 );
+
+golden!( day5_part1, aoc5::part1, "aoc5/fakedata.txt", "CMZ".to_string() );
+golden!( day5_part2, aoc5::part2, "aoc5/fakedata.txt", "MCD".to_string() );
+
+golden!( day6_part1, aoc6::part1, "aoc6/fakedata.txt",
+	aoc6::Markers( vec![ Some( 7 ), Some( 5 ), Some( 6 ), Some( 10 ), Some( 11 ) ] ) );
+golden!( day6_part2, aoc6::part2, "aoc6/fakedata.txt",
+	aoc6::Markers( vec![ Some( 19 ), Some( 23 ), Some( 23 ), Some( 29 ), Some( 26 ) ] ) );
+
+golden!( day7_part1, aoc7::part1, "aoc7/fakedata.txt", 95437 );
+golden!( day7_part2, aoc7::part2, "aoc7/fakedata.txt", 24933642 );
+
+golden!( day8_part1, aoc8::part1, "aoc8/fakedata.txt", 21 );
+golden!( day8_part2, aoc8::part2, "aoc8/fakedata.txt", 8 );
+
+golden!( day9_part1, aoc9::part1, "aoc9/fakedata.txt", 13 );
+golden!( day9_part2, aoc9::part2, "aoc9/fakedata.txt", 1 );
+golden!( day9_part1_larger, aoc9::part1, "aoc9/fakedata2.txt", 88 );
+golden!( day9_part2_larger, aoc9::part2, "aoc9/fakedata2.txt", 36 );
+
+golden!( day10_part1, aoc10::part1, "aoc10/fakedata.txt", 13140 );
+golden!( day10_part2, aoc10::part2, "aoc10/fakedata.txt", concat!(
+	"##..##..##..##..##..##..##..##..##..##..\n",
+	"###...###...###...###...###...###...###.\n",
+	"####....####....####....####....####....\n",
+	"#####.....#####.....#####.....#####.....\n",
+	"######......######......######......####\n",
+	"#######.......#######.......#######.....\n" ).to_string() );
+
+golden!( day11_part1, aoc11::part1, "aoc11/fakedata.txt", 10605 );
+golden!( day11_part2, aoc11::part2, "aoc11/fakedata.txt", 2713310158 );
+
+golden!( day12_part1, aoc12::part1, "aoc12/fakedata.txt", 31 );
+golden!( day12_part2, aoc12::part2, "aoc12/fakedata.txt", 29 );
+
+golden!( day13_part1, aoc13::part1, "aoc13/fakedata.txt", 13 );
+golden!( day13_part2, aoc13::part2, "aoc13/fakedata.txt", 140 );
+
+golden!( day14_part1, aoc14::part1, "aoc14/fakedata.txt", 24 );
+golden!( day14_part2, aoc14::part2, "aoc14/fakedata.txt", 93 );
+
+golden!( day15_part1, aoc15, | sensors | aoc15::no_beacon_count( &sensors, 10 ), "aoc15/fakedata.txt", 26 );
+golden!( day15_part2, aoc15, | sensors | aoc15::distress_frequency( &sensors, 20 ), "aoc15/fakedata.txt", 56000011 );
+
+golden!( day16_part1, aoc16::part1, "aoc16/fakedata.txt", 1651 );
+golden!( day16_part2, aoc16::part2, "aoc16/fakedata.txt", 1707 );
+
+golden!( day17_part1, aoc17::part1, "aoc17/fakedata.txt", 3068 );
+golden!( day17_part2, aoc17::part2, "aoc17/fakedata.txt", 1514285714288 );
+
+golden!( day18_part1, aoc18::part1, "aoc18/fakedata.txt", 64 );
+golden!( day18_part2, aoc18::part2, "aoc18/fakedata.txt", 58 );
+
+golden!( day19_part1, aoc19::part1, "aoc19/fakedata.txt", 33 );
+golden!( day19_part2, aoc19::part2, "aoc19/fakedata.txt", 56 * 62 );
+
+golden!( day20_part1, aoc20::part1, "aoc20/fakedata.txt", 3 );
+golden!( day20_part2, aoc20::part2, "aoc20/fakedata.txt", 1623178306 );
+golden!( day20_part2_negative, aoc20::part2, "aoc20/fakedata2.txt", 2434767459 );
+
+golden!( day21_part1, aoc21::part1, "aoc21/fakedata.txt", 152 );
+golden!( day21_part2, aoc21::part2, "aoc21/fakedata.txt", 301 );
+
+golden!( day22_part1, aoc22::part1, "aoc22/fakedata.txt", 6032 );
+golden!( day22_part2, aoc22::part2, "aoc22/fakedata.txt", 5031 );
+golden!( day22_part2_short_path, aoc22::part2, "aoc22/fakedata2.txt", 10067 );
+golden!( day22_part2_other_net, aoc22::part2, "aoc22/fakedata3.txt", 2029 );
+
+golden!( day23_part1, aoc23::part1, "aoc23/fakedata.txt", 110 );
+golden!( day23_part2, aoc23::part2, "aoc23/fakedata.txt", 20 );
+golden!( day23_part2_small, aoc23::part2, "aoc23/fakedata2.txt", 4 );
+
+golden!( day24_part1, aoc24::part1, "aoc24/fakedata.txt", 18 );
+golden!( day24_part2, aoc24::part2, "aoc24/fakedata.txt", 54 );
+
+golden!( day25_part1, aoc25::part1, "aoc25/fakedata.txt", "2=-1=0".parse::< aoc25::Snafu >().unwrap() );
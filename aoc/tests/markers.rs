@@ -0,0 +1,122 @@
+// Day 6's marker detector, fed whole streams, a byte at a time, and from
+// readers that hand over the input in awkward pieces.
+
+use std::io;
+use std::io::Read;
+use aoc6::Markers;
+
+fn sample() -> String
+{
+	return std::fs::read_to_string( concat!( env!( "CARGO_MANIFEST_DIR" ), "/../aoc6/fakedata.txt" ) ).unwrap();
+}
+
+// Hands over at most `chunk` bytes per read, and is sometimes interrupted
+struct Trickle< 'a >
+{
+	data: &'a [ u8 ],
+	chunk: usize,
+	reads: usize,
+}
+
+impl Read for Trickle< '_ >
+{
+	fn read( self: &mut Self, buf: &mut [ u8 ] ) -> io::Result< usize >
+	{
+		self.reads += 1;
+		if self.reads % 3 == 2
+		{
+			return Err( io::Error::from( io::ErrorKind::Interrupted ) );
+		}
+
+		let count = self.chunk.min( buf.len() ).min( self.data.len() );
+		buf[ ..count ].copy_from_slice( &self.data[ ..count ] );
+		self.data = &self.data[ count.. ];
+		return Ok( count );
+	}
+}
+
+#[test]
+fn scan_matches_parts()
+{
+	let input = sample();
+	let streams = aoc6::parse( &input ).unwrap();
+	let expected = vec![ aoc6::part1( &streams ).unwrap(), aoc6::part2( &streams ).unwrap() ];
+
+	assert_eq!( aoc6::scan( input.as_bytes(), &[ 4, 14 ] ).unwrap(), expected );
+	for chunk in [ 1, 2, 7, 1000 ]
+	{
+		let reader = Trickle { data: input.as_bytes(), chunk, reads: 0 };
+		assert_eq!( aoc6::scan( reader, &[ 4, 14 ] ).unwrap(), expected, "chunk {}", chunk );
+	}
+}
+
+#[test]
+fn scan_line_endings()
+{
+	let sizes = [ 3, 1, 5 ];
+	let expected = vec![
+		Markers( vec![ Some( 3 ), Some( 5 ) ] ),
+		Markers( vec![ Some( 1 ), Some( 1 ) ] ),
+		Markers( vec![ None, None ] ),
+	];
+	assert_eq!( aoc6::scan( "abcd\naaabc".as_bytes(), &sizes ).unwrap(), expected );
+	assert_eq!( aoc6::scan( "abcd\naaabc\n\nxyzwv\n".as_bytes(), &sizes ).unwrap(), expected );
+	assert_eq!( aoc6::scan( "".as_bytes(), &sizes ).unwrap(), vec![ Markers( vec![] ); 3 ] );
+}
+
+#[test]
+fn marker_at_the_very_end()
+{
+	assert_eq!( aoc6::first_markers( b"aaaabcd", &[ 4 ] ), vec![ Some( 7 ) ] );
+	assert_eq!( aoc6::first_markers( b"abcd", &[ 4, 5 ] ), vec![ Some( 4 ), None ] );
+}
+
+#[test]
+fn long_stream()
+{
+	// a million bytes that only just miss having 14 distinct in a row,
+	// then one that does
+	let mut stream: Vec< u8 > = ( 0..1_000_000 ).map( | i | b'a' + ( i % 13 ) as u8 ).collect();
+	stream.extend_from_slice( b"abcdefghijklmn" );
+	let found = aoc6::first_markers( &stream, &[ 13, 14, 15 ] );
+	assert_eq!( found, vec![ Some( 13 ), Some( 1_000_000 + 14 ), None ] );
+}
+
+#[test]
+fn every_occurrence()
+{
+	let lines = aoc6::scan_all( "abcabdd\naab\n".as_bytes(), &[ 3, 2 ] ).unwrap();
+	assert_eq!( lines, vec![
+		aoc6::LineMarkers { line: 1, length: 7, sizes: vec![ 3, 2 ], occurrences: vec![ vec![ 3, 4, 5, 6 ], vec![ 2, 3, 4, 5, 6 ] ] },
+		aoc6::LineMarkers { line: 2, length: 3, sizes: vec![ 3, 2 ], occurrences: vec![ vec![], vec![ 3 ] ] },
+	] );
+	assert_eq!( lines[ 0 ].gaps( 3 ), Some( vec![ 1, 1, 1 ] ) );
+	assert_eq!( lines[ 1 ].gaps( 2 ), Some( vec![] ) );
+	assert_eq!( lines[ 0 ].gaps( 4 ), None );
+
+	// the first of every occurrence is the first marker
+	let input = sample();
+	let first = aoc6::scan( input.as_bytes(), &[ 4, 14 ] ).unwrap();
+	for ( i, line ) in aoc6::scan_all( input.as_bytes(), &[ 4, 14 ] ).unwrap().iter().enumerate()
+	{
+		assert_eq!( line.occurrences[ 0 ].first().copied(), first[ 0 ].0[ i ] );
+		assert_eq!( line.occurrences[ 1 ].first().copied(), first[ 1 ].0[ i ] );
+	}
+}
+
+#[test]
+fn table_and_json()
+{
+	let lines = aoc6::scan_all( "abcabdd\naab\n".as_bytes(), &[ 3, 2 ] ).unwrap();
+	assert_eq!( aoc6::to_table( &lines ), concat!(
+		"line  size  count  markers\n",
+		"1     3     4      3, 4, 5, 6\n",
+		"1     2     5      2, 3, 4, 5, 6\n",
+		"2     3     0      \n",
+		"2     2     1      3\n",
+	) );
+	assert_eq!( aoc6::to_json( &lines ), concat!(
+		"[{\"line\":1,\"length\":7,\"markers\":{\"3\":[3,4,5,6],\"2\":[2,3,4,5,6]}},",
+		"{\"line\":2,\"length\":3,\"markers\":{\"3\":[],\"2\":[3]}}]\n",
+	) );
+}
@@ -0,0 +1,166 @@
+// Malformed inputs should come back as a ParseError pointing at the
+// offending line and column, never as a panic.
+
+use aoc_common::ParseError;
+
+fn expect_error< T >( result: Result< T, ParseError >, line: usize, column: usize, expected: &str )
+{
+	match result
+	{
+		Ok( _ ) => panic!( "expected an error at line {}, column {}", line, column ),
+		Err( e ) => assert_eq!( e, ParseError::new( line, column, expected ) ),
+	}
+}
+
+#[test]
+fn display()
+{
+	let e = ParseError::new( 3, 7, "a number" );
+	assert_eq!( e.to_string(), "line 3, column 7: expected a number" );
+}
+
+#[test]
+fn day2_bad_play()
+{
+	expect_error( aoc2::parse( "A Y\nD X\n" ), 2, 1, "A, B or C" );
+	expect_error( aoc2::parse( "A Y\nB Q\n" ), 2, 3, "X, Y or Z" );
+	expect_error( aoc2::parse( "A Y\nB X Z\n" ), 2, 4, "end of line" );
+}
+
+#[test]
+fn day4_backwards_range()
+{
+	expect_error( aoc4::parse( "2-4,6-8\n2-3,5-4\n" ), 2, 7, "a section at or after the start of the range" );
+	expect_error( aoc4::parse( "2-4;6-8\n" ), 1, 4, "`,`" );
+}
+
+#[test]
+fn day5_missing_stack()
+{
+	let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 3\n";
+	expect_error( aoc5::parse( input ), 4, 18, "a stack from 1 to 2" );
+
+	let input = "[A] [B]\n 1   5 \n\nmove 1 from 1 to 2\n";
+	expect_error( aoc5::parse( input ), 4, 18, "one of the stacks 1, 5" );
+}
+
+#[test]
+fn day5_bad_drawing()
+{
+	expect_error( aoc5::parse( "[A] [B]\n 1   x\n" ), 2, 6, "a stack number" );
+	expect_error( aoc5::parse( "[A] B\n 1   2\n" ), 1, 5, "`[`" );
+	expect_error( aoc5::parse( "[A] [B\n 1   2\n" ), 1, 7, "`]`" );
+	expect_error( aoc5::parse( "[A]     [B]\n 1   2\n" ), 1, 9, "a crate over exactly one stack number" );
+	expect_error( aoc5::parse( "\nmove 1 from 1 to 2\n" ), 1, 1, "a drawing of the stacks" );
+}
+
+#[test]
+fn day5_too_many_crates()
+{
+	// the first move leaves stack 1 empty
+	let input = "[A]\n[B] [C]\n 1   2\n\nmove 2 from 1 to 2\nmove 1 from 1 to 2\n";
+	expect_error( aoc5::parse( input ), 6, 6, "at most 0 crates, the height of stack 1" );
+}
+
+#[test]
+fn day11_bad_test_line()
+{
+	let input = "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  Test: divisible into 23\n";
+	expect_error( aoc11::parse( input ), 4, 1, "`Test: divisible by`" );
+}
+
+#[test]
+fn day11_throw_to_missing_monkey()
+{
+	let input = "Monkey 0:\n  Starting items: 79\n  Operation: new = old * old\n  Test: divisible by 23\n    If true: throw to monkey 0\n    If false: throw to monkey 4\n";
+	expect_error( aoc11::parse( input ), 6, 31, "a monkey from 0 to 0" );
+}
+
+#[test]
+fn day11_truncated()
+{
+	let input = "Monkey 0:\n  Starting items: 79\n";
+	expect_error( aoc11::parse( input ), 3, 1, "`Operation:`" );
+}
+
+#[test]
+fn day11_too_few_monkeys()
+{
+	expect_error( aoc11::parse( "" ), 1, 1, "`Monkey`" );
+	let input = "Monkey 0:\n  Starting items: 79\n  Operation: new = old * 19\n  Test: divisible by 23\n    If true: throw to monkey 0\n    If false: throw to monkey 0\n";
+	expect_error( aoc11::parse( input ), 7, 1, "another monkey" );
+}
+
+#[test]
+fn day13_unclosed_list()
+{
+	expect_error( aoc13::parse( "[1,[2,3]\n[1]\n" ), 1, 9, "one of `,`, `]`" );
+}
+
+#[test]
+fn day16_bad_valve()
+{
+	expect_error( aoc16::parse( "Valve AA has flow rate=x; tunnels lead to valves BB\n" ), 1, 24, "a number" );
+	expect_error( aoc16::parse( "Valve AA has flow rate=0; tunnel leads to valve ZZ\n" ), 1, 49, "a valve described in the input" );
+}
+
+#[test]
+fn day18_short_cube()
+{
+	expect_error( aoc18::parse( "1,2,3\n4,5\n" ), 2, 4, "`,`" );
+}
+
+#[test]
+fn day19_wrong_resource()
+{
+	let input = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 clay.";
+	expect_error( aoc19::parse( input ), 1, 65, "`ore. Each obsidian robot costs`" );
+}
+
+#[test]
+fn day21_unknown_monkey()
+{
+	expect_error( aoc21::parse( "root: aaaa + bbbb\naaaa: 4\nhumn: 5\n" ), 1, 14, "the name of a monkey in the input" );
+}
+
+#[test]
+fn day24_broken_wall()
+{
+	expect_error( aoc24::parse( "#.###\n#..>#\n#...<\n###.#\n" ), 3, 5, "`#`" );
+}
+
+#[test]
+fn day25_bad_digit()
+{
+	expect_error( aoc25::parse( "1=-0-2\n12311\n" ), 2, 3, "a SNAFU digit (`2`, `1`, `0`, `-` or `=`)" );
+	expect_error( aoc25::parse( "1=\n\n12\n" ), 2, 1, "a SNAFU number" );
+	expect_error( aoc25::parse( &"2".repeat( 60 ) ), 1, 1, "a SNAFU number that fits in 128 bits" );
+}
+
+#[test]
+fn day2_bad_rules()
+{
+	let moves = "move Rock A X 1\nmove Paper B Y 2\nmove Scissors C Z 3\n";
+	expect_error( aoc2::Game::parse( &format!( "{}lose X 0\nwin Z 6\n", moves ) ), 6, 1, "a `lose`, `draw` and `win` line" );
+	expect_error( aoc2::Game::parse( "move Rock A X 1\nmove Paper B Y 2\nlose X 0\ndraw Y 3\nwin Z 6\n" ), 6, 1, "an odd number of moves, at least three" );
+	expect_error( aoc2::Game::parse( "move Rock A X 1\nmove Paper A Y 2\n" ), 2, 12, "a letter not already used for Rock" );
+	expect_error( aoc2::Game::parse( "move Rock AB X 1\n" ), 1, 11, "a single letter" );
+	expect_error( aoc2::Game::parse( "tie X 3\n" ), 1, 1, "one of `move`, `lose`, `draw`, `win`" );
+	expect_error( aoc2::parse_with( aoc2::Game::parse( &format!( "{}lose X 0\ndraw Y 3\nwin W 6\n", moves ) ).unwrap(), "A Q\n" ), 1, 3, "X, Y, Z or W" );
+}
+
+#[test]
+fn day3_unknown_item()
+{
+	expect_error( aoc3::parse( "vJrwpWtwJgWrhcsFMMfFFhFp\nab1c\n" ), 2, 3, "an item from a to z or A to Z" );
+	expect_error( aoc3::parse_with( aoc3::Alphabet::from_items( "xyz" ).unwrap(), "xyzw\n" ), 1, 4, "one of `x`, `y`, `z`" );
+	expect_error( aoc3::Alphabet::parse( "a 1\nb two\n" ), 2, 3, "a number" );
+	expect_error( aoc3::Alphabet::parse( "a 1\na 2\n" ), 2, 1, "an item that isn't already in the table" );
+	expect_error( aoc3::Alphabet::parse( "é 53\n\nb 1\né 2\n" ), 4, 1, "an item that isn't already in the table" );
+	expect_error( aoc3::Alphabet::from_items( "xyzy" ), 1, 4, "an item that isn't already in the table" );
+	expect_error( aoc3::Alphabet::from_priorities( &[ ( 'x', 1 ), ( 'x', 2 ) ] ), 2, 1, "an item that isn't already in the table" );
+
+	// columns count characters after an item that takes several bytes
+	expect_error( aoc3::Alphabet::parse( "é 5x\n" ), 1, 4, "end of line" );
+	expect_error( aoc3::Alphabet::parse( "é x\n" ), 1, 3, "a number" );
+}
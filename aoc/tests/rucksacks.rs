@@ -0,0 +1,78 @@
+// Day 3's rucksacks with other group sizes and item alphabets.
+
+use aoc3::Alphabet;
+use aoc3::Anomaly;
+use aoc3::GroupReport;
+use aoc3::Totals;
+
+#[test]
+fn group_size()
+{
+	let rucksacks = aoc3::parse( "abac\ncdcb\ndbde\nxbxy\n" ).unwrap();
+
+	// a, c, d and x are each in both halves of one rucksack
+	assert_eq!( aoc3::solve( &rucksacks, 2 ), Some( Totals { compartments: 1 + 3 + 4 + 24, badges: 2 + 2 } ) );
+	assert_eq!( aoc3::solve( &rucksacks, 4 ), Some( Totals { compartments: 32, badges: 2 } ) );
+
+	// a group of three leaves the last rucksack out
+	assert_eq!( aoc3::solve( &rucksacks, 3 ).unwrap().badges, 2 );
+
+	assert_eq!( aoc3::solve( &rucksacks, 0 ), None );
+}
+
+#[test]
+fn big_alphabet()
+{
+	// more items than fit in a u64
+	let item = | i: u32 | char::from_u32( 0x100 + i ).unwrap();
+	let items: String = ( 0..100 ).map( item ).collect();
+	let alphabet = Alphabet::from_items( &items ).unwrap();
+	assert_eq!( alphabet.len(), 100 );
+
+	let input = format!( "{}{}{}{}\n", item( 99 ), item( 0 ), item( 1 ), item( 99 ) );
+	let rucksacks = aoc3::parse_with( alphabet, &input ).unwrap();
+	assert_eq!( aoc3::solve( &rucksacks, 1 ), Some( Totals { compartments: 100, badges: 1 } ) );
+}
+
+#[test]
+fn priority_table()
+{
+	let alphabet = Alphabet::parse( "x 10\ny -5\nπ 100\n" ).unwrap();
+	assert_eq!( alphabet.index_of( 'π' ), Some( 2 ) );
+	assert_eq!( alphabet.priority( 1 ), -5 );
+	assert_eq!( alphabet.item( 0 ), 'x' );
+
+	let rucksacks = aoc3::parse_with( alphabet, "πxyπ\nyxπy\n" ).unwrap();
+	assert_eq!( aoc3::solve( &rucksacks, 2 ), Some( Totals { compartments: 100 - 5, badges: 10 } ) );
+}
+
+#[test]
+fn validate()
+{
+	let sample = std::fs::read_to_string( concat!( env!( "CARGO_MANIFEST_DIR" ), "/../aoc3/fakedata.txt" ) ).unwrap();
+	let reports = aoc3::validate( &aoc3::parse( &sample ).unwrap(), 3 ).unwrap();
+	let badges: Vec< Option< char > > = reports.iter().map( | r | r.badge ).collect();
+	assert_eq!( badges, vec![ Some( 'r' ), Some( 'Z' ) ] );
+	assert!( reports.iter().all( | r | r.anomalies.len() == 0 ) );
+	assert_eq!( reports[ 1 ].members, vec![ 4, 5, 6 ] );
+
+	let reports = aoc3::validate( &aoc3::parse( "abcab\nxyzb\nqaqb\naqbq\n" ).unwrap(), 2 ).unwrap();
+	assert_eq!( reports.len(), 2 );
+	assert_eq!( reports[ 0 ], GroupReport
+	{
+		members: vec![ 1, 2 ],
+		badge: Some( 'b' ),
+		anomalies: vec![
+			Anomaly::OddLength( 1 ),
+			Anomaly::SeveralMisplaced( 1, vec![ 'a', 'b' ] ),
+			Anomaly::NothingMisplaced( 2 ),
+		],
+	} );
+	assert_eq!( reports[ 1 ].badge, None );
+	assert_eq!( reports[ 1 ].anomalies, vec![ Anomaly::SeveralBadges( vec![ 'a', 'b', 'q' ] ) ] );
+
+	let reports = aoc3::validate( &aoc3::parse( "abab\n" ).unwrap(), 3 ).unwrap();
+	assert_eq!( reports[ 0 ].anomalies.last(), Some( &Anomaly::IncompleteGroup ) );
+
+	assert_eq!( aoc3::validate( &aoc3::parse( &sample ).unwrap(), 0 ), None );
+}
@@ -0,0 +1,25 @@
+// Day 14's cave, for rock outside the area the sample uses.
+
+fn cave( input: &str ) -> Vec< Vec< ( usize, usize ) > >
+{
+	return aoc14::parse( input ).unwrap();
+}
+
+#[test]
+fn rock_far_to_the_right()
+{
+	let paths = cave( "498,4 -> 498,6\n1200,9 -> 1200,10\n" );
+	assert_eq!( aoc14::part1( &paths ), Some( 0 ) );
+
+	// a full pile down to the floor at 12, less the three rock cells in it
+	assert_eq!( aoc14::part2( &paths ), Some( 12 * 12 - 3 ) );
+}
+
+#[test]
+fn rock_far_below()
+{
+	// the pile on the floor at 602 spreads well past x = 0
+	let paths = cave( "498,4 -> 498,600\n" );
+	assert_eq!( aoc14::part1( &paths ), Some( 0 ) );
+	assert_eq!( aoc14::part2( &paths ), Some( 602 * 602 - 597 ) );
+}
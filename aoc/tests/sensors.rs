@@ -0,0 +1,15 @@
+// Day 15's distress beacon, when the sensors don't pin down one spot.
+
+#[test]
+fn more_than_one_gap()
+{
+	let sensors = aoc15::parse( "Sensor at x=0, y=0: closest beacon is at x=1, y=0\n" ).unwrap();
+	assert_eq!( aoc15::distress_frequency( &sensors, 2 ), None );
+}
+
+#[test]
+fn no_gap()
+{
+	let sensors = aoc15::parse( "Sensor at x=1, y=1: closest beacon is at x=3, y=1\n" ).unwrap();
+	assert_eq!( aoc15::distress_frequency( &sensors, 2 ), None );
+}
@@ -0,0 +1,47 @@
+// Converting day 25's balanced base 5 numbers to and from integers.
+
+use aoc25::Snafu;
+
+// Pairs from the puzzle's table
+const TABLE: [ ( i64, &str ); 12 ] = [
+	( 1, "1" ),
+	( 2, "2" ),
+	( 3, "1=" ),
+	( 4, "1-" ),
+	( 5, "10" ),
+	( 8, "2=" ),
+	( 10, "20" ),
+	( 15, "1=0" ),
+	( 20, "1-0" ),
+	( 2022, "1=11-2" ),
+	( 12345, "1-0---0" ),
+	( 314159265, "1121-1110-1=0" ),
+];
+
+#[test]
+fn decimal_to_snafu()
+{
+	for ( value, text ) in TABLE
+	{
+		assert_eq!( Snafu::from( value ).to_string(), text );
+		assert_eq!( text.parse::< Snafu >().unwrap(), Snafu::from( value ) );
+	}
+	assert_eq!( Snafu::from( 0i64 ).to_string(), "0" );
+}
+
+#[test]
+fn round_trips()
+{
+	// negative numbers just start with a - or =
+	for value in -1000..1000i64
+	{
+		let text = Snafu::from( value ).to_string();
+		assert_eq!( i64::try_from( text.parse::< Snafu >().unwrap() ), Ok( value ), "{} as {}", value, text );
+	}
+
+	for value in [ i128::MAX, i128::MIN, i64::MAX as i128 * 5 ]
+	{
+		let text = Snafu::from( value ).to_string();
+		assert_eq!( text.parse::< Snafu >().unwrap().value, value );
+	}
+}
@@ -0,0 +1,109 @@
+// Day 2's strategy guide, scored under both readings of its second column,
+// for the puzzle's game and for games loaded from a rules file.
+
+use aoc2::EResult;
+use aoc2::Game;
+use aoc2::RoundScore;
+
+fn rpsls() -> Game
+{
+	let rules = std::fs::read_to_string( concat!( env!( "CARGO_MANIFEST_DIR" ), "/../aoc2/rpsls.txt" ) ).unwrap();
+	return Game::parse( &rules ).unwrap();
+}
+
+fn score( as_play: i32, as_result: i32 ) -> RoundScore
+{
+	return RoundScore { as_play: Some( as_play ), as_result: Some( as_result ) };
+}
+
+#[test]
+fn per_round_breakdown()
+{
+	let guide = aoc2::parse( "A Y\nB X\nC Z\n" ).unwrap();
+	let scores = aoc2::score_rounds( &guide );
+	assert_eq!( scores, vec![ score( 8, 4 ), score( 1, 1 ), score( 6, 7 ) ] );
+	assert_eq!( aoc2::totals( &scores ), score( 15, 12 ) );
+
+	let lines: Vec< String > = guide.rounds.iter().map( | round | round.to_string() ).collect();
+	assert_eq!( lines, vec![ "A Y", "B X", "C Z" ] );
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock()
+{
+	let game = rpsls();
+	let index = | name: &str | game.moves.iter().position( | m | m.name == name ).unwrap();
+
+	let wins = [
+		( "Scissors", "Paper" ), ( "Paper", "Rock" ), ( "Rock", "Lizard" ), ( "Lizard", "Spock" ), ( "Spock", "Scissors" ),
+		( "Scissors", "Lizard" ), ( "Lizard", "Paper" ), ( "Paper", "Spock" ), ( "Spock", "Rock" ), ( "Rock", "Scissors" ),
+	];
+	for ( a, b ) in wins
+	{
+		assert!( aoc2::ABeatsB( &game, index( a ), index( b ) ), "{} beats {}", a, b );
+		assert!( !aoc2::ABeatsB( &game, index( b ), index( a ) ), "{} loses to {}", b, a );
+	}
+	assert_eq!( aoc2::Outcome( &game, index( "Spock" ), index( "Spock" ) ), EResult::Draw );
+
+	// both Paper and Lizard beat Spock, and Lizard scores more
+	assert_eq!( aoc2::PlayForResult( &game, index( "Spock" ), EResult::Win ), index( "Lizard" ) );
+}
+
+#[test]
+fn letters_that_only_mean_a_move()
+{
+	let guide = aoc2::parse_with( rpsls(), "A V\nE W\nD Z\n" ).unwrap();
+	let scores = aoc2::score_rounds( &guide );
+	assert_eq!( scores[ 0 ], RoundScore { as_play: Some( 4 ), as_result: None } );
+	assert_eq!( scores[ 1 ], RoundScore { as_play: Some( 2 ), as_result: None } );
+	assert_eq!( scores[ 2 ], score( 11, 11 ) );
+
+	assert_eq!( aoc2::totals( &scores ), RoundScore { as_play: Some( 17 ), as_result: None } );
+}
+
+#[test]
+fn standard_rules()
+{
+	let game = Game::standard();
+	assert_eq!( game.moves.len(), 3 );
+	assert_eq!( ( game.lose, game.draw, game.win ), ( ( 'X', 0 ), ( 'Y', 3 ), ( 'Z', 6 ) ) );
+}
+
+#[test]
+fn opponent_model()
+{
+	let guide = aoc2::parse( "A X\nA Y\nB Z\nC X\nA Z\n" ).unwrap();
+	assert_eq!( aoc2::opponent_counts( &guide ), vec![ 3, 1, 1 ] );
+
+	// Paper beats the three rocks: 3 * 8, plus 5 for the draw with Paper
+	// and 2 for losing to Scissors
+	assert_eq!( aoc2::best_fixed_response( &guide ), ( 1, 31 ) );
+
+	assert_eq!( aoc2::best_responses( &guide ), vec![ 1, 1, 2, 0, 1 ] );
+}
+
+#[test]
+fn tournament()
+{
+	let game = Game::standard();
+	let ( rock, paper, scissors ) = ( 0, 1, 2 );
+	let players = vec![
+		( "rock".to_string(), vec![ rock ] ),
+		( "paper".to_string(), vec![ paper, paper ] ),
+		( "cycle".to_string(), vec![ rock, paper, scissors ] ),
+	];
+
+	let standings = aoc2::tournament( &game, &players );
+	let names: Vec< &str > = standings.iter().map( | s | s.name.as_str() ).collect();
+	assert_eq!( names, vec![ "paper", "cycle", "rock" ] );
+
+	// paper beats rock 16 to 2 over two rounds, and draws with the cycle
+	// 8 + 5 + 2 to 1 + 5 + 9. The cycle beats rock 15 to 12, so it ties
+	// with paper on wins and points decide first place.
+	assert_eq!( standings[ 0 ], aoc2::Standing { name: "paper".to_string(), points: 16 + 15, wins: 1, draws: 1, losses: 0 } );
+	assert_eq!( standings[ 1 ], aoc2::Standing { name: "cycle".to_string(), points: 15 + 15, wins: 1, draws: 1, losses: 0 } );
+	assert_eq!( standings[ 2 ], aoc2::Standing { name: "rock".to_string(), points: 2 + 12, wins: 0, draws: 0, losses: 2 } );
+
+	let plays = aoc2::plays( &aoc2::parse( "A Y\nB Z\n" ).unwrap() );
+	assert_eq!( plays, Some( vec![ paper, scissors ] ) );
+}
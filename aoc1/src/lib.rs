@@ -0,0 +1,184 @@
+#![allow(non_snake_case)]
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use aoc_common::ParseError;
+
+// One elf's share of the snacks. Elves are numbered from 1 in the order
+// they appear in the input.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Elf
+{
+	pub index: usize,
+	pub items: usize,
+	pub total: i32,
+}
+
+// Reads elves one at a time from an input's lines, so a huge inventory
+// never has to be held in memory. `S` is &str for input already read in,
+// or String for lines coming straight off stdin.
+pub struct Elves< I >
+{
+	lines: I,
+	line: usize,
+	count: usize,
+	done: bool,
+}
+
+impl< I > Elves< I >
+{
+	pub fn new( lines: I ) -> Elves< I >
+	{
+		return Elves { lines, line: 0, count: 0, done: false };
+	}
+}
+
+impl< I, S > Iterator for Elves< I >
+	where I: Iterator< Item = S >, S: AsRef< str >
+{
+	type Item = Result< Elf, ParseError >;
+
+	fn next( self: &mut Self ) -> Option< Result< Elf, ParseError > >
+	{
+		if self.done
+		{
+			return None;
+		}
+
+		let mut curElfTotal = 0;
+		let mut curElfItems = 0;
+		while let Some( curLine ) = self.lines.next()
+		{
+			self.line += 1;
+			let curLine = curLine.as_ref();
+			if curLine.len() == 0
+			{
+				self.count += 1;
+				return Some( Ok( Elf { index: self.count, items: curElfItems, total: curElfTotal } ) );
+			}
+
+			match curLine.parse::<i32>()
+			{
+				Ok( cal ) =>
+				{
+					curElfTotal += cal;
+					curElfItems += 1;
+				},
+				Err( _ ) =>
+				{
+					self.done = true;
+					return Some( Err( ParseError::new( self.line, 1, "a calorie count" ) ) );
+				},
+			}
+		}
+		self.done = true;
+
+		// the last elf doesn't always get a blank line after it
+		if curElfItems > 0
+		{
+			self.count += 1;
+			return Some( Ok( Elf { index: self.count, items: curElfItems, total: curElfTotal } ) );
+		}
+
+		return None;
+	}
+}
+
+// Every elf, in the order they appear in the input
+pub fn parse( input: &str ) -> Result< Vec< Elf >, ParseError >
+{
+	return Elves::new( input.lines() ).collect();
+}
+
+// The `n` elves carrying the most, most first. Elves carrying the same
+// amount stay in input order.
+pub fn top( elves: &[ Elf ], n: usize ) -> Vec< &Elf >
+{
+	let mut ranked: Vec< &Elf > = elves.iter().collect();
+	ranked.sort_by_key( | elf | Reverse( elf.total ) );
+	ranked.truncate( n );
+	return ranked;
+}
+
+// What the top `n` elves are carrying between them, or None if there
+// aren't that many elves
+pub fn top_total( elves: &[ Elf ], n: usize ) -> Option< i32 >
+{
+	let ranked = top( elves, n );
+	if ranked.len() < n
+	{
+		return None;
+	}
+
+	return Some( ranked.iter().map( | elf | elf.total ).sum() );
+}
+
+// Keeps the `k` biggest elves seen so far in a min-heap, so the smallest
+// of them is the one to drop when a bigger elf turns up. Ties go to the
+// elf seen first, the same as top().
+pub struct TopElves
+{
+	k: usize,
+	heap: BinaryHeap< Reverse< ( i32, Reverse< usize >, usize ) > >,
+}
+
+impl TopElves
+{
+	pub fn new( k: usize ) -> TopElves
+	{
+		return TopElves { k, heap: BinaryHeap::with_capacity( k + 1 ) };
+	}
+
+	pub fn add( self: &mut Self, elf: &Elf )
+	{
+		self.heap.push( Reverse( ( elf.total, Reverse( elf.index ), elf.items ) ) );
+		if self.heap.len() > self.k
+		{
+			self.heap.pop();
+		}
+	}
+
+	// The elves kept, most first, like top()
+	pub fn ranked( self: &Self ) -> Vec< Elf >
+	{
+		// sorting the reversed entries puts the biggest first
+		let mut entries: Vec< &Reverse< ( i32, Reverse< usize >, usize ) > > = self.heap.iter().collect();
+		entries.sort();
+		return entries.iter()
+			.map( | Reverse( ( total, Reverse( index ), items ) ) | Elf { index: *index, items: *items, total: *total } )
+			.collect();
+	}
+}
+
+// top() for input that's too big to read in all at once, taking the
+// lines one at a time and only ever holding on to `k` elves
+pub fn top_streaming< I, S >( lines: I, k: usize ) -> Result< Vec< Elf >, ParseError >
+	where I: Iterator< Item = S >, S: AsRef< str >
+{
+	let mut top = TopElves::new( k );
+	for elf in Elves::new( lines )
+	{
+		top.add( &elf? );
+	}
+	return Ok( top.ranked() );
+}
+
+// One line per elf with a header, for pasting into a spreadsheet
+pub fn to_csv( elves: &[ Elf ] ) -> String
+{
+	let mut out = "elf,items,total\n".to_string();
+	for elf in elves
+	{
+		out.push_str( &format!( "{},{},{}\n", elf.index, elf.items, elf.total ) );
+	}
+	return out;
+}
+
+pub fn part1( elves: &[ Elf ] ) -> Option< i32 >
+{
+	return top_total( elves, 1 );
+}
+
+pub fn part2( elves: &[ Elf ] ) -> Option< i32 >
+{
+	return top_total( elves, 3 );
+}
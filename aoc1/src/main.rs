@@ -1,35 +1,140 @@
-use std::io::{ self, BufRead };
+use std::env;
+use std::io;
+use std::io::BufRead;
+use std::process;
+use aoc1::Elf;
 
-#[allow(non_snake_case)]
-fn main() 
+const USAGE: &str = "usage: aoc1 [--top N] [--csv | --stream]";
+
+struct Args
+{
+	top: Option< usize >,
+	csv: bool,
+	stream: bool,
+}
+
+fn parse_args() -> Result< Args, String >
 {
-	let mut lines = io::stdin().lock().lines();
+	let mut args = env::args().skip( 1 );
+
+	let mut top: Option< usize > = None;
+	let mut csv = false;
+	let mut stream = false;
+
+	while let Some( arg ) = args.next()
+	{
+		match arg.as_str()
+		{
+			"--top" =>
+			{
+				let value = args.next().ok_or( "--top needs a value" )?;
+				top = Some( value.parse::<usize>().map_err( |_| format!( "invalid count {}", value ) )? );
+			},
+			"--csv" => csv = true,
+			"--stream" => stream = true,
+			_ => return Err( format!( "unexpected argument {}", arg ) ),
+		}
+	}
 
-	let mut curElfTotal = 0;
-	let mut vec: Vec<i32> = Vec::new();
-	while let Some( line ) = lines.next()
+	if csv && stream
 	{
-		let curLine = line.unwrap();
+		return Err( "--csv needs every elf, so it can't be streamed".to_string() );
+	}
 
-		if curLine.len() == 0 
+	return Ok( Args { top, csv, stream } );
+}
+
+fn print_top( ranked: &[ &Elf ], n: usize )
+{
+	for elf in ranked
+	{
+		println!( "elf {}: {} items, {} calories", elf.index, elf.items, elf.total );
+	}
+	if ranked.len() == n
+	{
+		println!( "Top {} elves: {}", n, ranked.iter().map( | elf | elf.total ).sum::< i32 >() );
+	}
+}
+
+// Only the top elves are ever held in memory, however long the input is
+fn stream( top: Option< usize > )
+{
+	let lines = io::stdin().lock().lines().map( | line | match line
+	{
+		Ok( line ) => line,
+		Err( e ) =>
 		{
-			vec.push( curElfTotal );
-			curElfTotal = 0;
+			eprintln!( "failed to read input: {}", e );
+			process::exit( 1 );
 		}
-		else
+	} );
+
+	let ranked = match aoc1::top_streaming( lines, top.unwrap_or( 3 ) )
+	{
+		Ok( ranked ) => ranked,
+		Err( e ) =>
+		{
+			eprintln!( "{}", e );
+			process::exit( 1 );
+		}
+	};
+	let ranked: Vec< &Elf > = ranked.iter().collect();
+
+	match top
+	{
+		Some( n ) => print_top( &ranked, n ),
+		None =>
+		{
+			if let Some( elf ) = ranked.first()
+			{
+				println!( "Top elf: {}", elf.total );
+			}
+			if ranked.len() == 3
+			{
+				println!( "Top three elves: {}", ranked.iter().map( | elf | elf.total ).sum::< i32 >() );
+			}
+		},
+	}
+}
+
+fn main()
+{
+	let args = match parse_args()
+	{
+		Ok( args ) => args,
+		Err( e ) =>
 		{
-			let cal = curLine.parse::<i32>().unwrap();
-			//println!( "Cal: {}", cal );
-			curElfTotal += cal;
+			eprintln!( "{}\n{}", e, USAGE );
+			process::exit( 2 );
 		}
+	};
+
+	if args.stream
+	{
+		stream( args.top );
+		return;
 	}
 
-	vec.sort();
-	vec.reverse();
+	let elves = aoc_common::parse_stdin( aoc1::parse );
+
+	if args.csv
+	{
+		print!( "{}", aoc1::to_csv( &elves ) );
+		return;
+	}
 
-	if vec.len() >= 3
+	if let Some( n ) = args.top
+	{
+		print_top( &aoc1::top( &elves, n ), n );
+		return;
+	}
+
+	if let Some( total ) = aoc1::part1( &elves )
+	{
+		println!( "Top elf: {}", total );
+	}
+	if let Some( total ) = aoc1::part2( &elves )
 	{
-		let total = vec[0] + vec[1] + vec[2];
 		println!( "Top three elves: {}", total );
- 	}
+	}
 }
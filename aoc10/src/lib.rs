@@ -0,0 +1,110 @@
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+fn get_ss_sample( cycle: i32, x: i32 ) -> i32
+{
+	if cycle < 20 || cycle > 220
+	{
+		return 0;
+	}
+
+	if ( ( cycle - 20 ) % 40 ) == 0
+	{
+		return cycle * x;
+	}
+
+	return 0;
+}
+
+fn is_lit( cycle: i32, x: i32 ) -> bool
+{
+	let pos = ( (cycle - 1 ) % 40 ) + 1;
+	return pos >= x && pos <= x + 2;
+}
+	
+fn draw( cycle: i32, x: i32, out: &mut String )
+{
+	out.push( if is_lit( cycle, x ) { '#' } else { '.' } );
+	if ( cycle % 40 ) == 0
+	{
+		out.push( '\n' );
+	}
+}
+
+#[derive(Eq,PartialEq,Clone,Copy,Debug)]
+pub enum Instruction
+{
+	Noop,
+	Addx( i32 ),
+}
+
+pub fn parse( input: &str ) -> Result< Vec< Instruction >, ParseError >
+{
+	let mut program: Vec< Instruction > = Vec::new();
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		if cur_line.len() == 0
+		{
+			break;
+		}
+		
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		if scanner.one_of( &[ "noop", "addx " ] )? == 0
+		{
+			program.push( Instruction::Noop );
+		}
+		else
+		{
+			let n = scanner.number::<i32>()?;
+			program.push( Instruction::Addx( n ) );
+		}
+		scanner.end()?;
+	}
+	return Ok( program );
+}
+
+fn run( program: &[ Instruction ] ) -> ( i32, String )
+{
+	let mut cycle = 0;
+	let mut x = 1;
+	let mut ss = 0;
+	let mut out = String::new();
+
+	for instruction in program
+	{
+		match instruction
+		{
+			Instruction::Noop =>
+			{
+				cycle+=1;
+				draw( cycle, x, &mut out );
+				ss += get_ss_sample( cycle, x );
+			},
+			Instruction::Addx( n ) =>
+			{
+				cycle+=1;
+				draw( cycle, x, &mut out );
+				ss += get_ss_sample( cycle, x );
+				cycle+=1;
+				draw( cycle, x, &mut out );
+				ss += get_ss_sample( cycle, x );
+				
+				x += n;
+			},
+		}
+	}
+
+	return ( ss, out );
+}
+
+pub fn part1( program: &[ Instruction ] ) -> Option< i32 >
+{
+	let ( ss, _ ) = run( program );
+	return Some( ss );
+}
+
+pub fn part2( program: &[ Instruction ] ) -> Option< String >
+{
+	let ( _, out ) = run( program );
+	return Some( out );
+}
@@ -0,0 +1,227 @@
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+#[derive(Eq,PartialEq,Clone,Hash,Debug)]
+enum Operator
+{
+	MultParam,
+	AddParam,
+	Square,
+}
+	
+#[derive(Eq,PartialEq,Clone,Hash,Debug)]
+pub struct Monkey
+{
+	items: Vec<i64>,
+
+	operator: Operator,
+	param: i64,
+
+	divisor: i64,
+	throw_false: i32,
+	throw_true: i32,
+	
+	inspections: i64,
+}
+
+
+// Next line of a monkey description, or an error pointing just past the
+// end of the input if it ran out early
+fn next_line< 'a >( lines: &mut std::iter::Enumerate< std::str::Lines< 'a > >, line_count: usize, expected: &str ) -> Result< Scanner< 'a >, ParseError >
+{
+	return match lines.next()
+	{
+		Some( ( i, cur_line ) ) => Ok( Scanner::new( i + 1, cur_line ) ),
+		None => Err( ParseError::new( line_count + 1, 1, expected ) ),
+	};
+}
+
+pub fn parse( input: &str ) -> Result< Vec< Monkey >, ParseError >
+{
+	let line_count = input.lines().count();
+	let mut lines = input.lines().enumerate();
+
+	let mut monkey: Vec<Monkey> = Vec::new();
+	// line, column and target of every throw, checked once we know how many monkeys there are
+	let mut throws: Vec< ( usize, usize, i32 ) > = Vec::new();
+
+	while let Some( ( i, cur_line ) ) = lines.next()
+	{
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		scanner.literal( "Monkey " )?;
+		let column = scanner.column();
+		let id = scanner.number::<usize>()?;
+		if id != monkey.len()
+		{
+			return Err( scanner.error_at( column, &format!( "monkey {}", monkey.len() ) ) );
+		}
+		scanner.literal( ":" )?;
+		scanner.end()?;
+
+		// "  Starting items: 122, 345"
+		let mut scanner = next_line( &mut lines, line_count, "`Starting items:`" )?;
+		scanner.literal( "  Starting items:" )?;
+		let mut item_vec: Vec<i64> = Vec::new();
+		while !scanner.is_done()
+		{
+			scanner.literal( if item_vec.len() == 0 { " " } else { ", " } )?;
+			item_vec.push( scanner.number::<i64>()? );
+		}
+		
+		// "  Operation: new = old + 345" or "  Operation: new = old * old"
+		let mut scanner = next_line( &mut lines, line_count, "`Operation:`" )?;
+		scanner.literal( "  Operation: new = old " )?;
+		let mut op = match scanner.one_of( &[ "+ ", "* " ] )?
+		{
+			0 => Operator::AddParam,
+			_ => Operator::MultParam,
+		};
+		let mut param = 0;
+		if op == Operator::MultParam && scanner.optional( "old" )
+		{
+			op = Operator::Square;
+		}
+		else
+		{
+			param = scanner.number::<i64>()?;
+		}
+		scanner.end()?;
+
+		let mut scanner = next_line( &mut lines, line_count, "`Test:`" )?;
+		scanner.literal( "  Test: divisible by " )?;
+		let column = scanner.column();
+		let divisor = scanner.number::<i64>()?;
+		if divisor <= 0
+		{
+			return Err( scanner.error_at( column, "a positive divisor" ) );
+		}
+		scanner.end()?;
+
+		let mut scanner = next_line( &mut lines, line_count, "`If true:`" )?;
+		scanner.literal( "    If true: throw to monkey " )?;
+		let column = scanner.column();
+		let throw_true = scanner.number::<i32>()?;
+		throws.push( ( i + 5, column, throw_true ) );
+		scanner.end()?;
+
+		let mut scanner = next_line( &mut lines, line_count, "`If false:`" )?;
+		scanner.literal( "    If false: throw to monkey " )?;
+		let column = scanner.column();
+		let throw_false = scanner.number::<i32>()?;
+		throws.push( ( i + 6, column, throw_false ) );
+		scanner.end()?;
+
+		monkey.push( Monkey 
+		{
+			items: item_vec,
+			operator: op,
+			param: param,
+			divisor: divisor,
+			throw_true: throw_true,
+			throw_false: throw_false,
+			inspections: 0,
+		} );
+
+		match lines.next()
+		{
+			Some( ( i, cur_line ) ) =>
+			{
+				Scanner::new( i + 1, cur_line ).end()?;
+			},
+			None => break,
+		}
+	}
+
+	// monkeys can only throw to monkeys that exist
+	for ( line, column, target ) in throws
+	{
+		if target < 0 || target as usize >= monkey.len()
+		{
+			return Err( ParseError::new( line, column, &format!( "a monkey from 0 to {}", monkey.len() - 1 ) ) );
+		}
+	}
+
+	// monkey business needs the two busiest monkeys
+	if monkey.len() < 2
+	{
+		let expected = if monkey.len() == 0 { "`Monkey`" } else { "another monkey" };
+		return Err( ParseError::new( line_count + 1, 1, expected ) );
+	}
+
+	return Ok( monkey );
+}
+
+// With relief the worry level is divided by 3 after each inspection.
+// Without it the levels grow without bound, so they are kept modulo the
+// product of the divisors instead.
+fn monkey_business( monkeys: &[ Monkey ], rounds: usize, relief: bool ) -> i64
+{
+	let mut monkey = monkeys.to_vec();
+
+	let mut max_value = 1;
+	for m in &monkey
+	{
+		max_value *= m.divisor;
+	}
+
+	for _round in 0..rounds
+	{
+		for m in 0..monkey.len()
+		{
+			let mut throws: Vec<(usize, i64 ) > = Vec::new();
+			let monk = &mut monkey[ m ];
+			for item in &monk.items
+			{
+				let item = match monk.operator
+				{
+					Operator::AddParam => item + monk.param,
+					Operator::MultParam => item * monk.param,
+					Operator::Square => item * item,
+				};
+				let item = if relief
+				{
+					item / 3
+				}
+				else
+				{
+					// don't care about the actual value except as a multiple of the divisors
+					item % max_value
+				};
+
+				let target = if ( item % monk.divisor ) == 0 { monk.throw_true } else { monk.throw_false };
+
+				throws.push( ( target as usize, item ) );
+
+				monk.inspections += 1;
+			}
+			monk.items.clear();
+
+			for ( target, item ) in throws
+			{
+				monkey[ target ].items.push( item );
+			}
+
+		}
+	}
+	
+	let mut inspections: Vec<i64> = Vec::new();
+	for m in monkey
+	{
+		inspections.push( m.inspections );
+	}
+
+	inspections.sort();
+	inspections.reverse();
+
+	return inspections[0] * inspections[1];
+}
+
+pub fn part1( monkeys: &[ Monkey ] ) -> Option< i64 >
+{
+	return Some( monkey_business( monkeys, 20, true ) );
+}
+
+pub fn part2( monkeys: &[ Monkey ] ) -> Option< i64 >
+{
+	return Some( monkey_business( monkeys, 10000, false ) );
+}
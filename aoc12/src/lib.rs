@@ -0,0 +1,95 @@
+use aoc_common::Grid;
+use aoc_common::ParseError;
+use aoc_common::search;
+use aoc_common::search::SearchSpace;
+
+pub struct HeightMap
+{
+	grid: Grid< u32 >,
+	start: ( usize, usize ),
+	end: ( usize, usize ),
+}
+
+pub fn parse( input: &str ) -> Result< HeightMap, ParseError >
+{
+	let mut start: Option< ( usize, usize ) > = None;
+	let mut end: Option< ( usize, usize ) > = None;
+
+	let grid = Grid::parse( input, "a height from a to z, or a single S and E", | x, y, c |
+	{
+		let actual_c = match c
+		{
+			'S' if start.is_none() => 
+			{
+				start = Some( ( x, y ) );
+				'a'
+			},
+			'E' if end.is_none() => 
+			{
+				end = Some( ( x, y ) );
+				'z'
+			},
+			'a'..='z' => c,
+			_ => return None,
+		};
+			
+		Some( actual_c as u32 )
+	} )?;
+	let height = grid.height();
+
+	return match ( start, end )
+	{
+		( Some( start ), Some( end ) ) => Ok( HeightMap { grid, start, end } ),
+		( None, _ ) => Err( ParseError::new( height + 1, 1, "a start square marked S" ) ),
+		( _, None ) => Err( ParseError::new( height + 1, 1, "a best signal square marked E" ) ),
+	};
+}
+
+// Climbing from S to E, searched backwards from E so that one search
+// can look for either S or the nearest square at height a
+struct Descent< 'a >
+{
+	map: &'a HeightMap,
+	any_low_point: bool,
+}
+
+impl SearchSpace for Descent< '_ >
+{
+	type State = ( usize, usize );
+
+	fn successors( self: &Self, &( x, y ): &( usize, usize ) ) -> Vec< ( ( usize, usize ), usize ) >
+	{
+		let grid = &self.map.grid;
+
+		// we're walking backwards, so the step we came from can be at most one higher
+		return grid.neighbors4( x, y )
+			.filter( | n | grid[ ( x, y ) ] <= grid[ *n ] + 1 )
+			.map( | n | ( n, 1 ) )
+			.collect();
+	}
+
+	fn is_goal( self: &Self, pos: &( usize, usize ) ) -> bool
+	{
+		if self.any_low_point
+		{
+			return self.map.grid[ *pos ] == 'a' as u32;
+		}
+		return *pos == self.map.start;
+	}
+}
+
+fn shortest_climb( map: &HeightMap, any_low_point: bool ) -> Option< usize >
+{
+	let result = search::bfs( &Descent { map, any_low_point }, map.end );
+	return result.path.map( | path | path.cost );
+}
+
+pub fn part1( map: &HeightMap ) -> Option< usize >
+{
+	return shortest_climb( map, false );
+}
+
+pub fn part2( map: &HeightMap ) -> Option< usize >
+{
+	return shortest_climb( map, true );
+}
@@ -0,0 +1,202 @@
+use std::cmp::Ordering;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+#[derive(Eq,PartialEq,Clone,Hash,Debug)]
+pub enum Element
+{
+	List( Box< Vec< Element > > ),
+	Int( i32 ),
+}
+	
+
+fn parse_element( scanner: &mut Scanner ) -> Result< Element, ParseError >
+{
+	if scanner.optional( "[" )
+	{
+		let mut parsed: Box< Vec< Element > > = Box::default();
+		if scanner.optional( "]" )
+		{
+			return Ok( Element::List( parsed ) );
+		}
+
+		loop
+		{
+			parsed.push( parse_element( scanner )? );
+			if scanner.one_of( &[ ",", "]" ] )? == 1
+			{
+				break;
+			}
+		}
+		
+		return Ok( Element::List( parsed ) );
+	}
+
+	return match scanner.number::<i32>()
+	{
+		Ok( n ) => Ok( Element::Int( n ) ),
+		Err( _ ) => Err( scanner.error( "a number or `[`" ) ),
+	};
+}
+
+fn parse_packet( line: usize, s: &str ) -> Result< Element, ParseError >
+{
+	let mut scanner = Scanner::new( line, s );
+	let packet = parse_element( &mut scanner )?;
+	scanner.end()?;
+	return Ok( packet );
+}
+
+fn in_order( a: &Element, b: &Element ) -> Ordering
+{
+	//println!( "     {:?} <= {:?}", a, b );
+	match a
+	{
+		Element::Int( na ) =>
+		{
+			match b
+			{
+				Element::Int( nb ) => 
+				{
+					if na == nb
+					{
+						return Ordering::Equal;
+					}
+					else if na < nb
+					{
+						return Ordering::Less;
+					}
+					else
+					{
+						return Ordering::Greater;
+					}
+				},
+				Element::List( _ ) =>
+				{
+					let la = Element::List( Box::new( vec![ Element::Int( na.clone() ) ] ) );
+					return in_order( &la, b );
+				}
+			}
+		},
+
+		Element::List( la ) =>
+		{
+			match b
+			{
+				Element::Int( nb ) => 
+				{
+					let lb = Element::List( Box::new( vec![ Element::Int( nb.clone() ) ] ) );
+					return in_order( a, &lb );
+				},
+				Element::List( lb ) =>
+				{
+					let mut i = 0;
+					while i < la.len() && i < lb.len()
+					{
+						let res = in_order( &la[i], &lb[i] );
+						if res != Ordering::Equal
+						{
+							return res;
+						}
+
+						i += 1;
+					}
+
+					if la.len() == lb.len()
+					{
+						return Ordering::Equal;
+					}
+					else if la.len() < lb.len()
+					{
+						return Ordering::Less;
+					}
+					else
+					{
+						return Ordering::Greater;
+					}
+				}
+			}
+		}
+	}
+}
+
+pub fn parse( input: &str ) -> Result< Vec< ( Element, Element ) >, ParseError >
+{
+	let mut lines = input.lines().enumerate();
+
+	let mut pairs = Vec::new();
+
+	while let Some( ( i, cur_line ) ) = lines.next()
+	{
+		if cur_line.len() == 0
+		{
+			continue;
+		}
+
+		let a = parse_packet( i + 1, cur_line )?;
+
+		let b = match lines.next()
+		{
+			Some( ( i, cur_line ) ) => parse_packet( i + 1, cur_line )?,
+			None => return Err( ParseError::new( i + 2, 1, "the second packet of the pair" ) ),
+		};
+
+		pairs.push( ( a, b ) );
+	}
+
+	return Ok( pairs );
+}
+
+fn run( pairs: &[ ( Element, Element ) ] ) -> ( usize, usize )
+{
+	let mut index_sum = 0;
+
+	let first = Element::List( Box::new( vec![ Element::Int( 2 ) ] ) );
+	let second = Element::List( Box::new( vec![ Element::Int( 6 ) ] ) );
+	let mut packets = vec![
+		first.clone(),
+		second.clone(),
+	];
+
+	for ( i, ( a, b ) ) in pairs.iter().enumerate()
+	{
+		let res = in_order( a, b );
+
+		if res != Ordering::Greater
+		{
+			index_sum += i + 1;
+		}
+
+		packets.push( a.clone() );
+		packets.push( b.clone() );
+	}
+	
+	packets.sort_by( in_order );
+
+	let mut product = 1;
+	for i in 0..packets.len()
+	{
+		if in_order( &first, &packets[i] ) == Ordering::Equal
+		{
+			product *= i + 1;
+		}
+		else if in_order( &second, &packets[i] ) == Ordering::Equal
+		{
+			product *= i + 1;
+		}
+	}
+
+	return ( index_sum, product );
+}
+
+pub fn part1( pairs: &[ ( Element, Element ) ] ) -> Option< usize >
+{
+	let ( index_sum, _ ) = run( pairs );
+	return Some( index_sum );
+}
+
+pub fn part2( pairs: &[ ( Element, Element ) ] ) -> Option< usize >
+{
+	let ( _, product ) = run( pairs );
+	return Some( product );
+}
@@ -0,0 +1,174 @@
+use std::cmp;
+use aoc_common::Grid;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+fn parse_point( scanner: &mut Scanner ) -> Result< ( usize, usize ), ParseError >
+{
+	let x = scanner.number::<usize>()?;
+	scanner.literal( "," )?;
+	let y = scanner.number::<usize>()?;
+	return Ok( ( x, y ) );
+}
+
+pub fn parse( input: &str ) -> Result< Vec< Vec< ( usize, usize ) > >, ParseError >
+{
+	let mut paths = Vec::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		let mut path = vec![ parse_point( &mut scanner )? ];
+		while !scanner.is_done()
+		{
+			scanner.literal( " -> " )?;
+			let column = scanner.column();
+			let ( x, y ) = parse_point( &mut scanner )?;
+
+			// rock only runs horizontally or vertically
+			let ( prev_x, prev_y ) = path[ path.len() - 1 ];
+			if x != prev_x && y != prev_y
+			{
+				return Err( scanner.error_at( column, &format!( "a point in line with {},{}", prev_x, prev_y ) ) );
+			}
+			path.push( ( x, y ) );
+		}
+		paths.push( path );
+	}
+
+	return Ok( paths );
+}
+
+// Drop sand from 500,0 until it stops. Without a floor that's when the
+// first grain falls past the lowest rock; with the floor two below that
+// it's when the source itself is covered.
+fn drop_sand( paths: &[ Vec< ( usize, usize ) > ], floor: bool ) -> usize
+{
+	// The grid runs down to the floor, and wide enough for the rock and
+	// for sand piled on the floor, which spreads at most one column per
+	// row either side of the source. Columns are shifted by `left` so the
+	// pile can reach past x = 0.
+	let points = || paths.iter().flatten();
+	let lowest_rock = points().map( | ( _, y ) | *y ).max().unwrap_or( 0 ).max( 1 );
+	let height = lowest_rock + 3;
+	let left = cmp::min( points().map( | ( x, _ ) | *x as isize ).min().unwrap_or( 500 ), 500 - height as isize ) - 1;
+	let right = cmp::max( points().map( | ( x, _ ) | *x ).max().unwrap_or( 500 ), 500 + height ) + 1;
+	let width = ( right as isize - left + 1 ) as usize;
+	let column = | x: usize | -> usize { ( x as isize - left ) as usize };
+
+	let mut grid: Grid< char > = Grid::new( width, height, '.' );
+
+	let mut bottom = 1;
+
+	let blocked = | grid: &Grid< char >, x: usize, y: usize | -> bool { grid[ ( x, y ) ] != '.' };
+	let block = | grid: &mut Grid< char >, x: usize, y: usize, c: char |   
+	{ 
+		grid[ ( x, y ) ] = c; 
+	};
+
+
+	for points in paths
+	{
+		let ( mut prev_x, mut prev_y ) = points[0];
+		for i in 1..points.len()
+		{
+			let ( cur_x, cur_y ) = points[ i ];
+	
+			if cur_x == prev_x
+			{
+				// vertical line
+				let top_end = cmp::min( cur_y, prev_y );
+				let bottom_end = cmp::max( cur_y, prev_y );
+
+				for y in top_end..( bottom_end + 1 )
+				{
+					block( &mut grid, column( cur_x ), y, '|' );
+					bottom = cmp::max( bottom, y );
+				}
+			}
+			else
+			{
+				// horizontal line
+				assert!( cur_y == prev_y );
+
+				let left_end = cmp::min( cur_x, prev_x );
+				let right_end = cmp::max( cur_x, prev_x );
+
+				for x in left_end..( right_end + 1 )
+				{
+					block( &mut grid, column( x ), cur_y, '-' );
+					bottom = cmp::max( bottom, cur_y );
+				}
+			}
+					
+			prev_x = cur_x;
+			prev_y = cur_y;
+		}		
+	}
+
+	let rock_bottom = bottom;
+	bottom += 2;
+
+	if floor
+	{
+		for x in 0..width
+		{
+			block( &mut grid, x, bottom, '=' );
+		}
+	}
+
+	let mut sand_drops = 0;
+	let mut filled = false;
+	while !filled
+	{
+		let mut x = column( 500 );
+		let mut y = 0;
+		
+		while y <= bottom
+		{
+			if !floor && y > rock_bottom
+			{
+				// nothing left to land on
+				return sand_drops;
+			}
+
+			let down_clear: bool = { !blocked( &grid, x, y + 1 ) };
+			let left_down_clear: bool = { !blocked( &grid, x - 1, y + 1 ) };
+			let right_down_clear: bool = { !blocked( &grid, x + 1, y + 1 ) };
+			if down_clear
+			{
+				y += 1;
+			}
+			else if left_down_clear
+			{
+				x -= 1;
+				y += 1;
+			}
+			else if right_down_clear
+			{
+				x += 1;
+				y += 1;
+			}
+			else 
+			{
+				block( &mut grid, x, y, 'o' );
+				sand_drops += 1;
+				break;
+			}
+		}
+
+		filled = x == column( 500 ) && y == 0;
+	}
+
+	return sand_drops;
+}
+
+pub fn part1( paths: &[ Vec< ( usize, usize ) > ] ) -> Option< usize >
+{
+	return Some( drop_sand( paths, false ) );
+}
+
+pub fn part2( paths: &[ Vec< ( usize, usize ) > ] ) -> Option< usize >
+{
+	return Some( drop_sand( paths, true ) );
+}
@@ -0,0 +1,116 @@
+use aoc_common::Interval;
+use aoc_common::IntervalSet;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+#[derive(Eq,PartialEq,Clone,Hash,Debug)]
+pub struct Sensor
+{
+	sx: i64,
+	sy: i64,
+	bx: i64,
+	by: i64,
+}
+
+fn sensor_covers( sensor: &Sensor, y: i64 ) -> Option< Interval >
+{
+	let range = ( sensor.bx - sensor.sx ).abs() + ( sensor.by - sensor.sy ).abs();
+
+	let dist = ( sensor.sy - y ).abs();
+
+	let dx = range - dist;
+	//println!( " {:?} {} {} {}", sensor, range, dist, dx );
+	if dx < 0
+	{
+		return Option::None;
+	}
+
+	return Option::Some( Interval::new( sensor.sx - dx, sensor.sx + dx ) );
+}
+
+pub fn parse( input: &str ) -> Result< Vec< Sensor >, ParseError >
+{
+	let mut sensors: Vec<Sensor> = Vec::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		scanner.literal( "Sensor at x=" )?;
+		let sx = scanner.number::<i64>()?;
+		scanner.literal( ", y=" )?;
+		let sy = scanner.number::<i64>()?;
+		scanner.literal( ": closest beacon is at x=" )?;
+		let bx = scanner.number::<i64>()?;
+		scanner.literal( ", y=" )?;
+		let by = scanner.number::<i64>()?;
+		scanner.end()?;
+
+		sensors.push( Sensor { sx, sy, bx, by } );
+	}
+
+	return Ok( sensors );
+}
+
+// The real puzzle asks about row 2000000 and searches 0..=4000000. The
+// sample uses 10 and 20 instead, which its tests pass in themselves.
+const ROW: i64 = 2000000;
+const SEARCH_SIZE: i64 = 4000000;
+
+fn row_coverage( sensors: &[ Sensor ], y: i64 ) -> IntervalSet
+{
+	return sensors.iter().filter_map( | sensor | sensor_covers( sensor, y ) ).collect();
+}
+
+// positions in row y that are covered by a sensor and aren't a known beacon
+pub fn no_beacon_count( sensors: &[ Sensor ], y: i64 ) -> Option< i64 >
+{
+	let coverage = row_coverage( sensors, y );
+
+	let mut beacons: Vec< i64 > = Vec::new();
+	for sensor in sensors
+	{
+		if sensor.by == y && coverage.contains( sensor.bx ) && !beacons.contains( &sensor.bx )
+		{
+			beacons.push( sensor.bx );
+		}
+	}
+
+	return Some( coverage.len() - beacons.len() as i64 );
+}
+
+// the one spot from 0,0 to size,size that no sensor covers, or None if
+// there isn't exactly one
+pub fn distress_frequency( sensors: &[ Sensor ], size: i64 ) -> Option< i64 >
+{
+	let search_row = IntervalSet::from( Interval::new( 0, size ) );
+
+	let mut frequency = None;
+	for y in 0..( size + 1 )
+	{
+		let uncovered = search_row.difference( &row_coverage( sensors, y ) );
+		if uncovered.len() == 0
+		{
+			continue;
+		}
+
+		if uncovered.len() > 1 || frequency.is_some()
+		{
+			return None;
+		}
+
+		let x = uncovered.intervals()[0].start;
+		frequency = Some( 4000000 * x + y );
+	}
+
+	return frequency;
+}
+
+pub fn part1( sensors: &[ Sensor ] ) -> Option< i64 >
+{
+	return no_beacon_count( sensors, ROW );
+}
+
+pub fn part2( sensors: &[ Sensor ] ) -> Option< i64 >
+{
+	return distress_frequency( sensors, SEARCH_SIZE );
+}
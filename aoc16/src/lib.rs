@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::cmp;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+use aoc_common::search;
+use aoc_common::search::SearchSpace;
+
+
+
+type TunnelMap = HashMap<i32, i32>;
+
+
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Valve
+{
+	id: i32,
+	flow_rate: i32,
+	exits: TunnelMap,
+}
+
+pub type ValveMap = HashMap<i32, Valve>;
+
+fn name_to_id( name: &str ) -> i32
+{
+	assert!( name.len() == 2 );
+	let mut id: i32 = 0;
+	for c in name.chars()
+	{
+		id *= 100;
+		id += ( c as i32 - 'A' as i32 ) + 1;
+	}
+	return id;
+}
+
+const AA_ID: i32 = 101;
+
+fn id_to_name( id: i32 ) -> String
+{
+	let mut id = id;
+	let mut out: String = String::new();
+	while id != 0
+	{
+		let c = ( id % 100 - 1 ) + 'A' as i32;
+		out.insert( 0, c as u8 as char);
+
+		id /= 100;
+	}
+	return out;
+}
+
+fn parse_name( scanner: &mut Scanner ) -> Result< i32, ParseError >
+{
+	let column = scanner.column();
+	let name = scanner.word()?;
+	if name.len() != 2 || !name.chars().all( | c | c.is_ascii_uppercase() )
+	{
+		return Err( scanner.error_at( column, "a two letter valve name" ) );
+	}
+	return Ok( name_to_id( name ) );
+}
+
+impl Valve
+{
+	// Also returns the column of every exit, so the caller can complain
+	// about tunnels to valves that never get described
+	fn parse( scanner: &mut Scanner ) -> Result< ( Valve, Vec< ( usize, i32 ) > ), ParseError >
+	{
+		scanner.literal( "Valve " )?;
+		let id = parse_name( scanner )?;
+		scanner.literal( " has flow rate=" )?;
+		let flow_rate = scanner.number::<i32>()?;
+		scanner.one_of( &[ "; tunnels lead to valves ", "; tunnel leads to valve " ] )?;
+
+		let mut exits: TunnelMap = TunnelMap::new();
+		let mut exit_columns: Vec< ( usize, i32 ) > = Vec::new();
+		loop
+		{
+			let column = scanner.column();
+			let exit = parse_name( scanner )?;
+			exits.insert( exit, 1 );
+			exit_columns.push( ( column, exit ) );
+
+			if !scanner.optional( ", " )
+			{
+				break;
+			}
+		}
+		scanner.end()?;
+
+		let valve = Valve 
+		{
+			id,
+			flow_rate: flow_rate,
+			exits: exits,
+		};
+		return Ok( ( valve, exit_columns ) );
+	}
+}
+
+impl fmt::Display for Valve 
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		let mut s = String::from( "" );
+		let mut dests: Vec<String> = Vec::new();
+		for k in self.exits.keys()
+		{
+			dests.push( id_to_name( *k ) );
+		}
+		dests.sort();
+
+		for d in dests
+		{
+			let c = self.exits.get( &name_to_id( &d ) ).unwrap();
+			if s.len() > 0
+			{
+				s.push_str( "   " );
+			}
+
+			s.push_str( &format!( "{} {:2}", d, c ) );
+		}
+
+		f.write_fmt( format_args!( "V {} {:2}   {}", id_to_name( self.id ), self.flow_rate, s ) )
+	}
+}
+
+// Walking the tunnels from one valve, looking for `target`. Every tunnel
+// takes a minute.
+struct Tunnels< 'a >
+{
+	valves: &'a ValveMap,
+	target: i32,
+}
+
+impl SearchSpace for Tunnels< '_ >
+{
+	type State = i32;
+
+	fn successors( self: &Self, id: &i32 ) -> Vec< ( i32, usize ) >
+	{
+		return self.valves[ id ].exits.keys().map( | exit | ( *exit, 1 ) ).collect();
+	}
+
+	fn is_goal( self: &Self, id: &i32 ) -> bool
+	{
+		return *id == self.target;
+	}
+}
+
+// Only the valves with some flow are worth going to, so the cave comes
+// down to them, AA and how long it takes to walk between them
+struct Cave
+{
+	flows: Vec< i32 >,
+
+	// minutes from each good valve to each other, then from AA, or None
+	// if there's no way there
+	distances: Vec< Vec< Option< usize > > >,
+}
+
+impl Cave
+{
+	fn new( valves: &ValveMap ) -> Cave
+	{
+		let good = good_valves( valves );
+		let flows: Vec< i32 > = good.iter().map( | id | valves[ id ].flow_rate ).collect();
+
+		let mut distances: Vec< Vec< Option< usize > > > = Vec::new();
+		for from in good.iter().chain( [ &AA_ID ] )
+		{
+			distances.push( good.iter()
+				.map( | to | search::bfs( &Tunnels { valves, target: *to }, *from ).path.map( | path | path.cost ) )
+				.collect() );
+		}
+
+		return Cave { flows, distances };
+	}
+
+	fn start( self: &Self ) -> usize
+	{
+		return self.flows.len();
+	}
+}
+
+// Standing at a valve that's just been opened (or AA, to start with),
+// with `released` the pressure every open valve will have let out by the
+// time the minutes run out. `opened` has a bit for each good valve.
+#[derive(Eq,PartialEq,Clone,Hash,Debug)]
+struct Opening
+{
+	at: usize,
+	opened: u64,
+	minutes_left: usize,
+	released: i32,
+}
+
+impl SearchSpace for Cave
+{
+	type State = Opening;
+
+	// Walk to a valve that's still shut and open it
+	fn successors( self: &Self, state: &Opening ) -> Vec< ( Opening, usize ) >
+	{
+		let mut next: Vec< ( Opening, usize ) > = Vec::new();
+		for ( valve, flow ) in self.flows.iter().enumerate()
+		{
+			if state.opened & ( 1 << valve ) != 0
+			{
+				continue;
+			}
+
+			let minutes = match self.distances[ state.at ][ valve ]
+			{
+				Some( walk ) => walk + 1,
+				None => continue,
+			};
+			if minutes >= state.minutes_left
+			{
+				// it would be opened too late to let anything out
+				continue;
+			}
+
+			let minutes_left = state.minutes_left - minutes;
+			next.push( ( Opening
+			{
+				at: valve,
+				opened: state.opened | ( 1 << valve ),
+				minutes_left,
+				released: state.released + flow * minutes_left as i32,
+			}, minutes ) );
+		}
+		return next;
+	}
+
+	// flood() only wants every way the valves can be opened
+	fn is_goal( self: &Self, _state: &Opening ) -> bool
+	{
+		return false;
+	}
+}
+
+// Alone we get 30 minutes. Teaching the elephant costs 4 of them, and
+// then we each get 26 to split the valves between us.
+const SOLO_ROUNDS: usize = 30;
+const ELEPHANT_ROUNDS: usize = 26;
+
+// The most pressure that can be released opening exactly the valves in
+// each set, for every set that can be opened in time
+fn best_by_opened( cave: &Cave, minutes: usize ) -> HashMap< u64, i32 >
+{
+	let start = Opening { at: cave.start(), opened: 0, minutes_left: minutes, released: 0 };
+
+	let mut best: HashMap< u64, i32 > = HashMap::new();
+	for state in search::flood( cave, start )
+	{
+		let released = best.entry( state.opened ).or_insert( 0 );
+		*released = cmp::max( *released, state.released );
+	}
+	return best;
+}
+
+pub fn parse( input: &str ) -> Result< ValveMap, ParseError >
+{
+	let mut valves = HashMap::new();
+
+	let mut exits: Vec< ( usize, usize, i32 ) > = Vec::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		let ( valve, exit_columns ) = Valve::parse( &mut scanner )?;
+		for ( column, exit ) in exit_columns
+		{
+			exits.push( ( i + 1, column, exit ) );
+		}
+		valves.insert( valve.id, valve );
+	}
+
+	for ( line, column, exit ) in exits
+	{
+		if !valves.contains_key( &exit )
+		{
+			return Err( ParseError::new( line, column, "a valve described in the input" ) );
+		}
+	}
+	if !valves.contains_key( &AA_ID )
+	{
+		return Err( ParseError::new( input.lines().count() + 1, 1, "a description of valve AA" ) );
+	}
+
+	return Ok( valves );
+}
+
+fn good_valves( valves: &ValveMap ) -> Vec<i32>
+{
+	let mut good_valves: Vec<i32> = Vec::new();
+	for valve in valves.values()
+	{
+		if valve.flow_rate > 0
+		{
+			good_valves.push( valve.id );
+		}
+	}
+	return good_valves;
+}
+
+pub fn part1( valves: &ValveMap ) -> Option< i32 >
+{
+	let cave = Cave::new( valves );
+	return best_by_opened( &cave, SOLO_ROUNDS ).values().copied().max();
+}
+
+// We and the elephant open different valves, so the answer is the best
+// pair of sets that don't share any
+pub fn part2( valves: &ValveMap ) -> Option< i32 >
+{
+	let cave = Cave::new( valves );
+	let all: usize = ( 1 << cave.flows.len() ) - 1;
+
+	// the best for each set of valves, then for each set or any part of it
+	let mut best: Vec< i32 > = vec![ 0; all + 1 ];
+	for ( opened, released ) in best_by_opened( &cave, ELEPHANT_ROUNDS )
+	{
+		best[ opened as usize ] = released;
+	}
+	for valve in 0..cave.flows.len()
+	{
+		for set in 0..=all
+		{
+			if set & ( 1 << valve ) != 0
+			{
+				best[ set ] = cmp::max( best[ set ], best[ set ^ ( 1 << valve ) ] );
+			}
+		}
+	}
+
+	return ( 0..=all ).map( | mine | best[ mine ] + best[ all ^ mine ] ).max();
+}
@@ -0,0 +1,337 @@
+use std::cmp;
+use aoc_common::Grid;
+use aoc_common::ParseError;
+use aoc_common::cycle;
+
+
+const CHAMBER_WIDTH: usize = 7;
+const DEBUG_STUFF: bool = false;
+
+#[derive(Eq,PartialEq,Clone,Copy,Debug)]
+pub enum Dir
+{
+	Left,
+	Right,
+}
+
+#[derive(Eq,PartialEq,Clone,Copy,Debug)]
+enum RockType
+{
+	Horiz,
+	Plus,
+	Seven,
+	Vert,
+	Box,
+}
+
+
+macro_rules! rock_shape
+{
+	( $t: expr ) =>
+	{
+		match $t
+		{
+			RockType::Horiz => vec![ ( 0, 0 ), ( 1, 0 ), ( 2, 0 ), ( 3, 0 ) ],
+			RockType::Plus => vec![ ( 1, 0 ), ( 0, 1 ), ( 1, 1 ), ( 2, 1 ), ( 1, 2 ) ],
+			RockType::Seven => vec![ ( 0, 0 ), ( 1, 0 ), ( 2, 0 ), ( 2, 1 ), ( 2, 2 ) ],
+			RockType::Vert => vec![ ( 0, 0 ), ( 0, 1 ), ( 0, 2 ), ( 0, 3 ) ],
+			RockType::Box => vec![ ( 0, 0 ), ( 1, 0 ), ( 0, 1 ), ( 1, 1 ) ],
+		}
+	}
+}
+	
+type RockShape = Vec< ( usize, usize ) >;
+
+#[derive(Eq,PartialEq,Clone,Debug)]
+struct Rock
+{
+	rock_type: RockType,
+	rock_shape: RockShape,
+	x: usize,
+	y: usize,
+}
+
+impl Rock
+{
+	fn new( rock_type: RockType, y: usize ) -> Rock
+	{
+		return Rock
+		{
+			x: 2,
+			y: y,
+			rock_type: rock_type,
+			rock_shape: rock_shape!( rock_type ),
+		};
+	}
+
+	fn draw( self: &Self, grid: &mut Chamber )
+	{
+		for p in &self.rock_shape
+		{
+			let x = self.x + p.0;
+			let y = self.y + p.1;
+
+			grid.set( x, y );
+		}
+	}
+
+
+	fn will_fit( self: &Self, dx: isize, dy: isize, grid: &Chamber ) -> bool
+	{
+		let x: isize = self.x as isize + dx;
+		let y: isize = self.y as isize + dy;
+		for p in &self.rock_shape
+		{
+			let x = x + p.0 as isize;
+			let y = y + p.1 as isize;
+
+			if x < 0 || x >= CHAMBER_WIDTH as isize
+			{
+				return false;
+			}
+
+			if y < 0 
+			{
+				return false;
+			}
+
+			if grid.is_set( x as usize, y as usize )
+			{
+				return false;
+			}
+		}
+
+		return true;
+	}
+
+	fn fall( self: &mut Self, grid: &Chamber ) -> bool
+	{
+		if self.will_fit( 0, -1, grid )
+		{
+			if DEBUG_STUFF { println!( "Rock falls" ); }
+			self.y -= 1;
+			return true;
+		}
+		else
+		{
+			if DEBUG_STUFF { println!( "Rock tries to fall, but nothing happens" ); }
+		}
+
+		return false;
+	}
+
+
+	fn push( self: &mut Self, dir: Dir, grid: &Chamber )
+	{
+		let dx: isize = match dir 
+		{
+	 		Dir::Left => -1,
+			Dir::Right => 1,
+		};
+
+		if self.will_fit( dx, 0, grid )
+		{
+			if DEBUG_STUFF { println!( "Jet pushes {:?}", dir ); }
+			self.x = ( ( self.x as isize ) + dx ) as usize;
+		}
+		else
+		{
+			if DEBUG_STUFF { println!( "Jet pushes {:?}, but nothing happens", dir ); }
+		}
+	}
+
+	fn dump( self: &Self, grid: &Chamber )
+	{
+		if !DEBUG_STUFF 
+		{
+			return;
+		}
+		let mut vis_grid = grid.clone();
+		self.draw( &mut vis_grid );
+		vis_grid.dump();
+		println!();
+	}
+
+}
+
+// The rocks settled so far. Only rows below max_height have anything in them.
+#[derive(Eq,PartialEq,Clone,Debug)]
+struct Chamber
+{
+	max_height: usize,
+	cells: Grid< bool >,
+}
+
+impl Chamber
+{
+	fn new() -> Chamber
+	{
+		return Chamber
+		{
+			max_height: 0,
+			cells: Grid::new( CHAMBER_WIDTH, 0, false ),
+		};
+	}
+
+	// Make sure there are at least `height` rows to drop rocks into
+	fn make_room( self: &mut Self, height: usize )
+	{
+		if height > self.cells.height()
+		{
+			let more = cmp::max( height - self.cells.height(), self.cells.height() );
+			self.cells.add_rows( more, false );
+		}
+	}
+
+	fn is_set( self: &Self, x: usize, y: usize ) -> bool
+	{
+		return self.cells[ ( x, y ) ];
+	}
+
+	fn set( self: &mut Self, x: usize, y: usize )
+	{
+		assert!( !self.is_set( x, y ) );
+		self.cells[ ( x, y ) ] = true;
+		self.max_height = cmp::max( self.max_height, y + 1 );
+	}
+
+	fn row ( self: &Self, y: usize ) -> &[ bool ]
+	{
+		return self.cells.row( y );
+	}
+
+	fn dump( self: &Self )
+	{
+		for y in ( 0.. self.max_height ).rev()
+		{
+			let mut out = "|".to_string();
+			for x in 0..CHAMBER_WIDTH
+			{
+				out.push( if self.is_set( x, y ) { '#' } else { '.' } );
+			}
+			out.push( '|' );
+			println!( "{}", out );
+		}
+
+		let mut out = "+".to_string();
+		for _ in 0..CHAMBER_WIDTH
+		{
+			out.push( '-' );
+		}
+		out.push( '+' );
+		println!( "{}", out );
+	}
+}
+
+
+pub fn parse( input: &str ) -> Result< Vec< Dir >, ParseError >
+{
+	let mut pattern: Vec< Dir > = Vec::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		if pattern.len() != 0 && cur_line.len() != 0
+		{
+			return Err( ParseError::new( i + 1, 1, "the jet pattern on a single line" ) );
+		}
+		for ( x, c ) in cur_line.char_indices()
+		{
+			match c
+			{
+				'<' => pattern.push( Dir::Left ),
+				'>' => pattern.push( Dir::Right ),
+				_ => return Err( ParseError::new( i + 1, x + 1, "`<` or `>`" ) ),
+			}
+		}
+	}
+
+	if pattern.len() == 0
+	{
+		return Err( ParseError::new( 1, 1, "a jet pattern" ) );
+	}
+
+	return Ok( pattern );
+}
+
+const ROCK_PATTERN: [ RockType; 5 ] = [ RockType::Horiz, RockType::Plus, RockType::Seven, RockType::Vert, RockType::Box ];
+
+// Drop one rock and let the jets push it around until it comes to rest.
+// `rounds` counts jets used so far, so it picks up where the last rock
+// left off in the pattern.
+fn drop_rock( rock_type: RockType, pattern: &[ Dir ], rounds: &mut usize, grid: &mut Chamber )
+{
+	// the tallest rock is four rows, and it starts three above the tower
+	grid.make_room( grid.max_height + 7 );
+	let mut rock = Rock::new( rock_type, grid.max_height + 3 );
+	if DEBUG_STUFF { println!( "NEW ROCK!" ); }
+
+	loop
+	{
+		rock.dump( grid );
+		rock.push( pattern[ *rounds % pattern.len() ], grid );
+		rock.dump( grid );
+		*rounds += 1;
+		if !rock.fall( grid )
+		{
+			rock.draw( grid );
+			if DEBUG_STUFF { grid.dump(); }
+			break;
+		}
+	}
+}
+
+// Everything that decides how the tower grows from here: the chamber, and
+// how far through the rock and jet patterns we are
+#[derive(Eq,PartialEq,Clone,Debug)]
+struct Tower
+{
+	chamber: Chamber,
+	rocks: usize,
+	rounds: usize,
+}
+
+impl Tower
+{
+	fn drop_rock( self: &mut Self, pattern: &[ Dir ] )
+	{
+		drop_rock( ROCK_PATTERN[ self.rocks % ROCK_PATTERN.len() ], pattern, &mut self.rounds, &mut self.chamber );
+		self.rocks += 1;
+	}
+
+	// Where we are in both patterns, plus the top of the tower. Rocks
+	// can't fall past much of it, so two towers with the same top grow
+	// the same way from there on.
+	fn key( self: &Self, pattern: &[ Dir ] ) -> ( usize, usize, Vec< u8 > )
+	{
+		let top = self.chamber.max_height.saturating_sub( SURFACE_ROWS )..self.chamber.max_height;
+		let surface: Vec< u8 > = top
+			.map( | y | self.chamber.row( y ).iter().fold( 0, | bits, set | bits << 1 | *set as u8 ) )
+			.collect();
+
+		return ( self.rocks % ROCK_PATTERN.len(), self.rounds % pattern.len(), surface );
+	}
+}
+
+// How many rows from the top of the tower go into its key
+const SURFACE_ROWS: usize = 32;
+
+fn tower_height( pattern: &[ Dir ], rock_count: u64 ) -> usize
+{
+	let tower = Tower { chamber: Chamber::new(), rocks: 0, rounds: 0 };
+	let height = cycle::extrapolate( tower, rock_count,
+		| tower | tower.drop_rock( pattern ),
+		| tower | tower.key( pattern ),
+		| tower | tower.chamber.max_height as i64 );
+
+	return height as usize;
+}
+
+pub fn part1( pattern: &[ Dir ] ) -> Option< usize >
+{
+	return Some( tower_height( pattern, 2022 ) );
+}
+
+pub fn part2( pattern: &[ Dir ] ) -> Option< usize >
+{
+	return Some( tower_height( pattern, 1000000000000 ) );
+}
@@ -0,0 +1,229 @@
+use std::fmt;
+use std::cmp;
+use std::collections::HashSet;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+use aoc_common::search;
+use aoc_common::search::SearchSpace;
+
+
+#[derive(Eq,PartialEq,Clone,Copy,Hash,Debug)]
+struct CubeIterator
+{
+	x: i32,
+	y: i32,
+	z: i32,
+	n: i32,
+}
+
+impl Iterator for CubeIterator
+{
+	type Item = Cube;
+
+	fn next( &mut self ) -> Option<Cube>
+	{
+		let mut dx = 0;
+		let mut dy = 0;
+		let mut dz = 0;
+		match self.n
+		{
+			0 => dx = 1,
+			1 => dx = -1,
+			2 => dy = 1,
+			3 => dy = -1,
+			4 => dz = 1,
+			5 => dz = -1,
+			_ => return None,
+		}
+		self.n += 1;
+
+		return Some( Cube{ x: self.x + dx, y: self.y + dy, z: self.z + dz } );
+	}
+}
+
+#[derive(Eq,PartialEq,Clone,Copy,Hash,Debug)]
+pub struct Cube
+{
+	x: i32,
+	y: i32,
+	z: i32,
+}
+
+impl Cube
+{
+	fn parse( scanner: &mut Scanner ) -> Result< Cube, ParseError >
+	{
+		let x = scanner.number::<i32>()?;
+		scanner.literal( "," )?;
+		let y = scanner.number::<i32>()?;
+		scanner.literal( "," )?;
+		let z = scanner.number::<i32>()?;
+		scanner.end()?;
+		return Ok( Cube { x, y, z } );
+	}
+
+	fn neighbors( self: &Self ) -> CubeIterator
+	{
+		return CubeIterator{ x: self.x, y: self.y, z: self.z, n: 0 };
+	}
+}
+
+impl fmt::Display for Cube
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		f.write_fmt( format_args!( "( {}, {}, {} )", self.x, self.y, self.z ) )
+	}
+}
+
+#[derive(Eq,PartialEq,Clone,Copy,Hash,Debug)]
+struct Bounds
+{
+	min_x: i32,
+	min_y: i32,
+	min_z: i32,
+	max_x: i32,
+	max_y: i32,
+	max_z: i32,
+}
+
+impl Bounds
+{
+	fn new( cubes: &HashSet< Cube > ) -> Bounds
+	{
+		let mut bounds = Bounds
+		{
+			min_x: i32::MAX,
+			min_y: i32::MAX,
+			min_z: i32::MAX,
+			max_x: i32::MIN,
+			max_y: i32::MIN,
+			max_z: i32::MIN,
+		};
+
+		for cube in cubes
+		{
+			bounds.min_x = cmp::min( bounds.min_x, cube.x );
+			bounds.min_y = cmp::min( bounds.min_y, cube.y );
+			bounds.min_z = cmp::min( bounds.min_z, cube.z );
+			bounds.max_x = cmp::max( bounds.max_x, cube.x );
+			bounds.max_y = cmp::max( bounds.max_y, cube.y );
+			bounds.max_z = cmp::max( bounds.max_z, cube.z );
+		}
+
+		return bounds;
+	}
+
+	fn grow( self: &Self, n: i32 ) -> Bounds
+	{
+		return Bounds
+		{
+			min_x: self.min_x - n,
+			min_y: self.min_y - n,
+			min_z: self.min_z - n,
+			max_x: self.max_x + n,
+			max_y: self.max_y + n,
+			max_z: self.max_z + n,
+		};
+	}
+
+	fn contains( self: &Self, cube: &Cube ) -> bool
+	{
+		return self.min_x <= cube.x
+			&& self.min_y <= cube.y
+			&& self.min_z <= cube.z
+			&& self.max_x >= cube.x
+			&& self.max_y >= cube.y
+			&& self.max_z >= cube.z;
+	}
+}
+
+// The air around the droplet, inside a box one bigger than it on every
+// side so the air can flow all the way round
+struct Outside< 'a >
+{
+	cubes: &'a HashSet< Cube >,
+	bounds: Bounds,
+}
+
+impl SearchSpace for Outside< '_ >
+{
+	type State = Cube;
+
+	fn successors( self: &Self, node: &Cube ) -> Vec< ( Cube, usize ) >
+	{
+		return node.neighbors()
+			.filter( | n | self.bounds.contains( n ) && !self.cubes.contains( n ) )
+			.map( | n | ( n, 1 ) )
+			.collect();
+	}
+
+	fn is_goal( self: &Self, _node: &Cube ) -> bool
+	{
+		return false;
+	}
+}
+
+fn count_faces( cubes: &HashSet<Cube> ) -> i32
+{
+	let mut empty_faces: i32 = 0;
+	for cube in cubes
+	{
+		for n in cube.neighbors()
+		{
+			if !cubes.contains( &n )
+			{
+				empty_faces += 1;
+			}
+		}
+	}
+
+	return empty_faces;
+}
+
+
+pub fn parse( input: &str ) -> Result< HashSet< Cube >, ParseError >
+{
+	let mut cubes: HashSet<Cube> = HashSet::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		let cube = Cube::parse( &mut Scanner::new( i + 1, cur_line ) )?;
+		cubes.insert( cube );
+	}
+
+	return Ok( cubes );
+}
+
+pub fn part1( cubes: &HashSet< Cube > ) -> Option< i32 >
+{
+	return Some( count_faces( cubes ) );
+}
+
+// only count the faces the outside air can reach, not the ones facing
+// into pockets
+pub fn part2( cubes: &HashSet< Cube > ) -> Option< i32 >
+{
+	if cubes.len() == 0
+	{
+		return Some( 0 );
+	}
+
+	let bounds = Bounds::new( cubes ).grow( 1 );
+	let start = Cube { x: bounds.min_x, y: bounds.min_y, z: bounds.min_z };
+	let outside = search::flood( &Outside { cubes, bounds }, start );
+
+	let mut outside_faces: i32 = 0;
+	for cube in cubes
+	{
+		for n in cube.neighbors()
+		{
+			if outside.contains( &n )
+			{
+				outside_faces += 1;
+			}
+		}
+	}
+
+	return Some( outside_faces );
+}
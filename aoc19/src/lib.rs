@@ -0,0 +1,354 @@
+use std::fmt;
+use std::cmp;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+use aoc_common::search;
+use aoc_common::search::SearchSpace;
+
+#[derive(Eq,PartialEq,Clone,Copy,Hash,Debug)]
+pub struct Blueprint
+{
+	id: u32,
+	ore_cost_ore: u32,
+	clay_cost_ore: u32,
+	obsidian_cost_ore: u32,
+	obsidian_cost_clay: u32,
+	geode_cost_ore: u32,
+	geode_cost_obsidian: u32,
+}
+
+impl Blueprint
+{
+	fn parse( scanner: &mut Scanner ) -> Result< Blueprint, ParseError >
+	{
+		scanner.literal( "Blueprint " )?;
+		let id = scanner.number::<u32>()?;
+		scanner.literal( ": Each ore robot costs " )?;
+		let ore_cost_ore = scanner.number::<u32>()?;
+		scanner.literal( " ore. Each clay robot costs " )?;
+		let clay_cost_ore = scanner.number::<u32>()?;
+		scanner.literal( " ore. Each obsidian robot costs " )?;
+		let obsidian_cost_ore = scanner.number::<u32>()?;
+		scanner.literal( " ore and " )?;
+		let obsidian_cost_clay = scanner.number::<u32>()?;
+		scanner.literal( " clay. Each geode robot costs " )?;
+		let geode_cost_ore = scanner.number::<u32>()?;
+		scanner.literal( " ore and " )?;
+		let geode_cost_obsidian = scanner.number::<u32>()?;
+		scanner.literal( " obsidian." )?;
+		scanner.end()?;
+	
+		return Ok( Blueprint
+		{
+			id,
+			ore_cost_ore,
+			clay_cost_ore,
+			obsidian_cost_ore,
+			obsidian_cost_clay,
+			geode_cost_ore,
+			geode_cost_obsidian,
+		} );
+	}
+}
+
+impl fmt::Display for Blueprint
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		f.write_fmt( format_args!( "BP {}: ore {}  clay {} ore   obsidian {} ore {} clay   geode {} ore, {} obsidian",
+			self.id,
+			self.ore_cost_ore,
+			self.clay_cost_ore,
+			self.obsidian_cost_ore, self.obsidian_cost_clay,
+			self.geode_cost_ore, self.geode_cost_obsidian, ) )
+	}
+}
+
+
+// How things stand after `minute` minutes
+#[derive(Eq,PartialEq,Clone,Hash,Debug)]
+struct State
+{
+	minute: u32,
+
+	ore: u32,
+	clay: u32,
+	obsidian: u32,
+	geode: u32,
+
+	ore_robots: u32,
+	clay_robots: u32,
+	obsidian_robots: u32,
+	geode_robots: u32,
+}
+
+impl fmt::Display for State
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		f.write_fmt( format_args!( "min {:2}: res  {:2}/{:2}/{:2}/{:2} robots {:2}/{:2}/{:2}/{:2}",
+			self.minute,
+			self.ore,
+			self.clay,
+			self.obsidian,
+			self.geode,
+			self.ore_robots,
+			self.clay_robots,
+			self.obsidian_robots,
+			self.geode_robots ) )
+	}
+}
+
+#[derive(Eq,PartialEq,Clone,Copy,Debug)]
+enum Robot
+{
+	Ore,
+	Clay,
+	Obsidian,
+	Geode,
+}
+
+// Running one blueprint's factory as a search. Each step either saves up
+// for and builds the next robot, or sits out the rest of the time.
+//
+// astar() finds the cheapest path, so a minute costs the most geodes it
+// could have cracked, geode_ceiling( minute_limit ), less the ones it did.
+// The cheapest path to the end is the one with the most geodes.
+struct Factory
+{
+	blueprint: Blueprint,
+	minute_limit: u32,
+}
+
+// More geode robots than there are minutes can never be built, so every
+// minute's cost stays positive
+fn geode_ceiling( minute_limit: u32 ) -> u32
+{
+	return 2 * minute_limit;
+}
+
+impl Factory
+{
+	fn cost( self: &Self, robot: Robot ) -> ( u32, u32, u32 )
+	{
+		let bp = &self.blueprint;
+		return match robot
+		{
+			Robot::Ore => ( bp.ore_cost_ore, 0, 0 ),
+			Robot::Clay => ( bp.clay_cost_ore, 0, 0 ),
+			Robot::Obsidian => ( bp.obsidian_cost_ore, bp.obsidian_cost_clay, 0 ),
+			Robot::Geode => ( bp.geode_cost_ore, 0, bp.geode_cost_obsidian ),
+		};
+	}
+
+	// Only so much of each resource can be spent in a minute, so there's
+	// no point having more robots than that making it
+	fn worth_building( self: &Self, state: &State, robot: Robot ) -> bool
+	{
+		let bp = &self.blueprint;
+		return match robot
+		{
+			Robot::Ore => state.ore_robots < bp.ore_cost_ore.max( bp.clay_cost_ore ).max( bp.obsidian_cost_ore ).max( bp.geode_cost_ore ),
+			Robot::Clay => state.clay_robots < bp.obsidian_cost_clay,
+			Robot::Obsidian => state.obsidian_robots < bp.geode_cost_obsidian,
+			Robot::Geode => true,
+		};
+	}
+
+	// Let `minutes` pass, paying the geodes not cracked on the way
+	fn advance( self: &Self, state: &State, minutes: u32 ) -> ( State, usize )
+	{
+		let mut next = state.clone();
+		next.minute += minutes;
+		next.ore += state.ore_robots * minutes;
+		next.clay += state.clay_robots * minutes;
+		next.obsidian += state.obsidian_robots * minutes;
+		next.geode += state.geode_robots * minutes;
+
+		let cost = ( geode_ceiling( self.minute_limit ) - state.geode_robots ) * minutes;
+		return ( next, cost as usize );
+	}
+}
+
+// Minutes of saving until `have` plus `rate` a minute reaches `need`, or
+// None if it never will
+fn minutes_to_save( need: u32, have: u32, rate: u32 ) -> Option< u32 >
+{
+	if have >= need
+	{
+		return Some( 0 );
+	}
+	if rate == 0
+	{
+		return None;
+	}
+	return Some( ( need - have ).div_ceil( rate ) );
+}
+
+impl SearchSpace for Factory
+{
+	type State = State;
+
+	fn successors( self: &Self, state: &State ) -> Vec< ( State, usize ) >
+	{
+		let mut next: Vec< ( State, usize ) > = Vec::new();
+		if state.minute >= self.minute_limit
+		{
+			return next;
+		}
+
+		for robot in [ Robot::Geode, Robot::Obsidian, Robot::Clay, Robot::Ore ]
+		{
+			if !self.worth_building( state, robot )
+			{
+				continue;
+			}
+
+			let ( ore, clay, obsidian ) = self.cost( robot );
+			let saving = [
+				minutes_to_save( ore, state.ore, state.ore_robots ),
+				minutes_to_save( clay, state.clay, state.clay_robots ),
+				minutes_to_save( obsidian, state.obsidian, state.obsidian_robots ),
+			];
+			let wait = match saving.iter().try_fold( 0, | most, minutes | minutes.map( | m | cmp::max( most, m ) ) )
+			{
+				Some( wait ) => wait,
+				None => continue,
+			};
+
+			// a robot that's finished in the last minute never gets to
+			// make anything
+			let minutes = wait + 1;
+			if state.minute + minutes >= self.minute_limit
+			{
+				continue;
+			}
+
+			let ( mut built, cost ) = self.advance( state, minutes );
+			built.ore -= ore;
+			built.clay -= clay;
+			built.obsidian -= obsidian;
+			match robot
+			{
+				Robot::Ore => built.ore_robots += 1,
+				Robot::Clay => built.clay_robots += 1,
+				Robot::Obsidian => built.obsidian_robots += 1,
+				Robot::Geode => built.geode_robots += 1,
+			}
+			next.push( ( built, cost ) );
+		}
+
+		next.push( self.advance( state, self.minute_limit - state.minute ) );
+		return next;
+	}
+
+	fn is_goal( self: &Self, state: &State ) -> bool
+	{
+		return state.minute == self.minute_limit;
+	}
+
+	// As if a geode robot could be built every minute from now on. Each
+	// minute that adds at most one more robot, so the estimate never drops
+	// by more than a minute costs and astar() can trust it.
+	fn heuristic( self: &Self, state: &State ) -> usize
+	{
+		let left = self.minute_limit - state.minute;
+		let most = state.geode_robots * left + left * left.saturating_sub( 1 ) / 2;
+		return ( geode_ceiling( self.minute_limit ) * left ).saturating_sub( most ) as usize;
+	}
+}
+
+// Part 1 scores every blueprint over 24 minutes. Part 2 only gets the
+// first three blueprints, but 32 minutes to run them.
+const QUALITY_MINUTE_LIMIT: u32 = 24;
+const MINUTE_LIMIT: u32 = 32;
+const BLUEPRINT_LIMIT: usize = 3;
+
+fn most_geodes( blueprint: &Blueprint, minute_limit: u32 ) -> u32
+{
+	let factory = Factory { blueprint: *blueprint, minute_limit };
+	let start = State
+	{
+		minute: 0,
+		ore: 0,
+		clay: 0,
+		obsidian: 0,
+		geode: 0,
+		ore_robots: 1,
+		clay_robots: 0,
+		obsidian_robots: 0,
+		geode_robots: 0,
+	};
+
+	return match search::astar( &factory, start ).path
+	{
+		Some( path ) => path.states[ path.states.len() - 1 ].geode,
+		None => 0,
+	};
+}
+
+use std::thread;
+
+pub fn parse( input: &str ) -> Result< Vec< Blueprint >, ParseError >
+{
+	let mut blueprints: Vec< Blueprint > = Vec::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		blueprints.push( Blueprint::parse( &mut Scanner::new( i + 1, cur_line ) )? );
+	}
+
+	return Ok( blueprints );
+}
+
+// ( id, best geode count ) for each blueprint, one thread per blueprint
+fn best_geode_counts( blueprints: &[ Blueprint ], minute_limit: u32 ) -> Vec< ( u32, u32 ) >
+{
+	let mut threads: Vec< thread::JoinHandle< ( u32, u32 ) > > = Vec::new();
+	for bp in blueprints
+	{
+		let bp = bp.clone();
+
+		let handle = thread::spawn( move || -> ( u32, u32 )
+		{
+			let best_geode_count = most_geodes( &bp, minute_limit );
+			return ( bp.id, best_geode_count );
+		} );
+		threads.push( handle );
+	}
+
+	let mut counts: Vec< ( u32, u32 ) > = Vec::new();
+	for handle in threads
+	{
+		counts.push( handle.join().unwrap() );
+	}
+
+	return counts;
+}
+
+pub fn part1( blueprints: &[ Blueprint ] ) -> Option< u32 >
+{
+	let mut quality_level_sum = 0;
+
+	for ( bpid, geode_count ) in best_geode_counts( blueprints, QUALITY_MINUTE_LIMIT )
+	{
+		quality_level_sum += bpid * geode_count;
+	}
+
+	return Some( quality_level_sum );
+}
+
+pub fn part2( blueprints: &[ Blueprint ] ) -> Option< u32 >
+{
+	let blueprints = &blueprints[ ..cmp::min( blueprints.len(), BLUEPRINT_LIMIT ) ];
+
+	let mut geode_count_product = 1;
+
+	for ( _bpid, geode_count ) in best_geode_counts( blueprints, MINUTE_LIMIT )
+	{
+		geode_count_product *= geode_count;
+	}
+
+	return Some( geode_count_product );
+}
+
@@ -0,0 +1,485 @@
+#![allow(non_snake_case)]
+use std::cmp::Reverse;
+use std::fmt;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+#[derive(Eq,PartialEq,Copy,Clone,Debug)]
+pub enum EResult
+{
+	Win,
+	Lose,
+	Draw,
+}
+
+// One move in a game, with the letters the two columns of a strategy
+// guide use for it
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Move
+{
+	pub name: String,
+	pub them: char,
+	pub me: char,
+	pub score: i32,
+}
+
+// A cyclic game like Rock Paper Scissors. Moves are listed so that each
+// one beats the moves an odd number of places before it, wrapping round,
+// which is why there has to be an odd number of them. Rock Paper Scissors
+// Lizard Spock is Rock, Paper, Scissors, Spock, Lizard in that order.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Game
+{
+	pub moves: Vec< Move >,
+
+	// letter and score for losing, drawing and winning
+	pub lose: ( char, i32 ),
+	pub draw: ( char, i32 ),
+	pub win: ( char, i32 ),
+}
+
+const STANDARD_RULES: &str = "\
+move Rock A X 1
+move Paper B Y 2
+move Scissors C Z 3
+lose X 0
+draw Y 3
+win Z 6
+";
+
+fn ParseLetter( scanner: &mut Scanner ) -> Result< char, ParseError >
+{
+	let column = scanner.column();
+	let word = scanner.word()?;
+	if word.chars().count() != 1
+	{
+		return Err( scanner.error_at( column, "a single letter" ) );
+	}
+	return Ok( word.chars().next().unwrap() );
+}
+
+impl Game
+{
+	// The puzzle's game: A/B/C for the elf, X/Y/Z for us
+	pub fn standard() -> Game
+	{
+		return Game::parse( STANDARD_RULES ).unwrap();
+	}
+
+	// Rules files have one line per move, in cycle order, then one for
+	// each result. Blank lines and lines starting with # are skipped.
+	//
+	//   move Rock A X 1
+	//   lose X 0
+	pub fn parse( rules: &str ) -> Result< Game, ParseError >
+	{
+		let mut moves: Vec< Move > = Vec::new();
+		let mut results: [ Option< ( char, i32 ) >; 3 ] = [ None; 3 ];
+		let mut line_count = 0;
+
+		for ( i, cur_line ) in rules.lines().enumerate()
+		{
+			line_count = i + 1;
+			if cur_line.len() == 0 || cur_line.starts_with( '#' )
+			{
+				continue;
+			}
+
+			let mut scanner = Scanner::new( i + 1, cur_line );
+			let kind = scanner.one_of( &[ "move ", "lose ", "draw ", "win " ] )?;
+			if kind == 0
+			{
+				let name = scanner.word()?.to_string();
+				scanner.literal( " " )?;
+				let them_column = scanner.column();
+				let them = ParseLetter( &mut scanner )?;
+				scanner.literal( " " )?;
+				let me_column = scanner.column();
+				let me = ParseLetter( &mut scanner )?;
+				scanner.literal( " " )?;
+				let score = scanner.number::< i32 >()?;
+				scanner.end()?;
+
+				if let Some( other ) = moves.iter().find( | m | m.them == them )
+				{
+					return Err( scanner.error_at( them_column, &format!( "a letter not already used for {}", other.name ) ) );
+				}
+				if let Some( other ) = moves.iter().find( | m | m.me == me )
+				{
+					return Err( scanner.error_at( me_column, &format!( "a letter not already used for {}", other.name ) ) );
+				}
+				moves.push( Move { name, them, me, score } );
+			}
+			else
+			{
+				let letter_column = scanner.column();
+				let letter = ParseLetter( &mut scanner )?;
+				scanner.literal( " " )?;
+				let score = scanner.number::< i32 >()?;
+				scanner.end()?;
+
+				if results.iter().flatten().any( | r | r.0 == letter )
+				{
+					return Err( scanner.error_at( letter_column, "a letter not already used for a result" ) );
+				}
+				results[ kind - 1 ] = Some( ( letter, score ) );
+			}
+		}
+
+		// anything missing is reported just past the end of the file
+		if moves.len() < 3 || moves.len() % 2 != 1
+		{
+			return Err( ParseError::new( line_count + 1, 1, "an odd number of moves, at least three" ) );
+		}
+
+		return match results
+		{
+			[ Some( lose ), Some( draw ), Some( win ) ] => Ok( Game { moves, lose, draw, win } ),
+			_ => Err( ParseError::new( line_count + 1, 1, "a `lose`, `draw` and `win` line" ) ),
+		};
+	}
+
+	fn result_letter( self: &Self, c: char ) -> Option< EResult >
+	{
+		if c == self.lose.0
+		{
+			return Some( EResult::Lose );
+		}
+		else if c == self.draw.0
+		{
+			return Some( EResult::Draw );
+		}
+		else if c == self.win.0
+		{
+			return Some( EResult::Win );
+		}
+		return None;
+	}
+}
+
+// "A, B or C"
+fn ListLetters( letters: &[ char ] ) -> String
+{
+	let names: Vec< String > = letters.iter().map( | c | c.to_string() ).collect();
+	return match names.split_last()
+	{
+		Some( ( last, [] ) ) => last.clone(),
+		Some( ( last, rest ) ) => format!( "{} or {}", rest.join( ", " ), last ),
+		None => String::new(),
+	};
+}
+
+pub fn ComputePlayScore( game: &Game, play: usize ) -> i32
+{
+	return game.moves[ play ].score;
+}
+
+pub fn ComputeResultScore( game: &Game, result: EResult ) -> i32
+{
+	match result
+	{
+		EResult::Win => return game.win.1,
+		EResult::Lose => return game.lose.1,
+		EResult::Draw => return game.draw.1,
+	}
+}
+
+// How `a` does against `b`
+pub fn Outcome( game: &Game, a: usize, b: usize ) -> EResult
+{
+	let places = ( a + game.moves.len() - b ) % game.moves.len();
+	if places == 0
+	{
+		return EResult::Draw;
+	}
+	else if places % 2 == 1
+	{
+		return EResult::Win;
+	}
+	return EResult::Lose;
+}
+
+pub fn ABeatsB( game: &Game, a: usize, b: usize ) -> bool
+{
+	return Outcome( game, a, b ) == EResult::Win;
+}
+
+// The move that gets `result` against `them`. With more than three moves
+// there's a choice, so take the one that scores best.
+pub fn PlayForResult( game: &Game, them: usize, result: EResult ) -> usize
+{
+	let mut best: Option< usize > = None;
+	for play in 0..game.moves.len()
+	{
+		if Outcome( game, play, them ) != result
+		{
+			continue;
+		}
+		match best
+		{
+			Some( b ) if ComputePlayScore( game, b ) >= ComputePlayScore( game, play ) => {},
+			_ => best = Some( play ),
+		}
+	}
+
+	// every move draws against itself and, with an odd number of moves,
+	// wins against something and loses against something
+	return best.unwrap();
+}
+
+pub fn ComputeScore( game: &Game, me: usize, them: usize ) -> i32
+{
+	return ComputePlayScore( game, me ) + ComputeResultScore( game, Outcome( game, me, them ) );
+}
+
+// The second column is either the play to make (part 1) or the result
+// to aim for (part 2), so keep both readings of it. With more moves than
+// results a letter might only make sense one way.
+#[derive(Eq,PartialEq,Copy,Clone,Debug)]
+pub struct Round
+{
+	pub them: usize,
+	pub me: Option< usize >,
+	pub result: Option< EResult >,
+	letters: ( char, char ),
+}
+
+impl fmt::Display for Round
+{
+	// The line from the strategy guide this round came from
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		f.pad( &format!( "{} {}", self.letters.0, self.letters.1 ) )
+	}
+}
+
+// A strategy guide and the game it's for
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Guide
+{
+	pub game: Game,
+	pub rounds: Vec< Round >,
+}
+
+pub fn parse( input: &str ) -> Result< Guide, ParseError >
+{
+	return parse_with( Game::standard(), input );
+}
+
+pub fn parse_with( game: Game, input: &str ) -> Result< Guide, ParseError >
+{
+	let them_letters: Vec< char > = game.moves.iter().map( | m | m.them ).collect();
+	let mut me_letters: Vec< char > = game.moves.iter().map( | m | m.me ).collect();
+	for c in [ game.lose.0, game.draw.0, game.win.0 ]
+	{
+		if !me_letters.contains( &c )
+		{
+			me_letters.push( c );
+		}
+	}
+
+	let mut rounds: Vec< Round > = Vec::new();
+	for ( i, curLine ) in input.lines().enumerate()
+	{
+		if curLine.len() == 0
+		{
+			break;
+		}
+
+		let chars: Vec< char > = curLine.chars().collect();
+		let them = match chars.first().and_then( | c | them_letters.iter().position( | t | t == c ) )
+		{
+			Some( them ) => them,
+			None => return Err( ParseError::new( i + 1, 1, &ListLetters( &them_letters ) ) ),
+		};
+		if chars.get( 1 ) != Some( &' ' )
+		{
+			return Err( ParseError::new( i + 1, 2, "a space" ) );
+		}
+		let second = match chars.get( 2 )
+		{
+			Some( c ) if me_letters.contains( c ) => *c,
+			_ => return Err( ParseError::new( i + 1, 3, &ListLetters( &me_letters ) ) ),
+		};
+		if chars.len() > 3
+		{
+			return Err( ParseError::new( i + 1, 4, "end of line" ) );
+		}
+
+		let me = game.moves.iter().position( | m | m.me == second );
+		let result = game.result_letter( second );
+		rounds.push( Round { them, me, result, letters: ( chars[ 0 ], second ) } );
+	}
+	return Ok( Guide { game, rounds } );
+}
+
+// What one round scores under each reading of the second column, or None
+// where the letter doesn't mean anything under that reading
+#[derive(Eq,PartialEq,Copy,Clone,Debug)]
+pub struct RoundScore
+{
+	pub as_play: Option< i32 >,
+	pub as_result: Option< i32 >,
+}
+
+fn ScoreRound( game: &Game, round: &Round ) -> RoundScore
+{
+	return RoundScore
+	{
+		as_play: round.me.map( | me | ComputeScore( game, me, round.them ) ),
+		as_result: round.result.map( | result | ComputeResultScore( game, result ) + ComputePlayScore( game, PlayForResult( game, round.them, result ) ) ),
+	};
+}
+
+// Both scores for every round, in one pass over the guide
+pub fn score_rounds( guide: &Guide ) -> Vec< RoundScore >
+{
+	return guide.rounds.iter().map( | round | ScoreRound( &guide.game, round ) ).collect();
+}
+
+// The totals under both readings, summed over the rounds. A reading that
+// doesn't work for every round has no total.
+pub fn totals( scores: &[ RoundScore ] ) -> RoundScore
+{
+	return RoundScore
+	{
+		as_play: scores.iter().map( | score | score.as_play ).sum(),
+		as_result: scores.iter().map( | score | score.as_result ).sum(),
+	};
+}
+
+pub fn part1( guide: &Guide ) -> Option< i32 >
+{
+	return totals( &score_rounds( guide ) ).as_play;
+}
+
+pub fn part2( guide: &Guide ) -> Option< i32 >
+{
+	return totals( &score_rounds( guide ) ).as_result;
+}
+
+// How many times the elf plays each move in the guide
+pub fn opponent_counts( guide: &Guide ) -> Vec< usize >
+{
+	let mut counts = vec![ 0; guide.game.moves.len() ];
+	for round in &guide.rounds
+	{
+		counts[ round.them ] += 1;
+	}
+	return counts;
+}
+
+// Our best move against `them`. Usually that's a win, but with custom
+// scores a draw or even a loss with a valuable move can come out ahead.
+pub fn BestResponse( game: &Game, them: usize ) -> usize
+{
+	let mut best = 0;
+	for play in 1..game.moves.len()
+	{
+		if ComputeScore( game, play, them ) > ComputeScore( game, best, them )
+		{
+			best = play;
+		}
+	}
+	return best;
+}
+
+// The one move that does best if we play it every round against the elf's
+// moves as the guide has them, and what it scores in total. Dividing by
+// the number of rounds gives the expected score per round.
+pub fn best_fixed_response( guide: &Guide ) -> ( usize, i32 )
+{
+	let counts = opponent_counts( guide );
+	let mut best: Option< ( usize, i32 ) > = None;
+	for play in 0..guide.game.moves.len()
+	{
+		let mut total = 0;
+		for them in 0..counts.len()
+		{
+			total += counts[ them ] as i32 * ComputeScore( &guide.game, play, them );
+		}
+
+		match best
+		{
+			Some( ( _, best_total ) ) if best_total >= total => {},
+			_ => best = Some( ( play, total ) ),
+		}
+	}
+
+	// games always have at least three moves
+	return best.unwrap();
+}
+
+// Our best move for each round, knowing what the elf will play
+pub fn best_responses( guide: &Guide ) -> Vec< usize >
+{
+	return guide.rounds.iter().map( | round | BestResponse( &guide.game, round.them ) ).collect();
+}
+
+// The second column read as the moves to play, for pitting guides against
+// each other. None if some letter isn't a move.
+pub fn plays( guide: &Guide ) -> Option< Vec< usize > >
+{
+	return guide.rounds.iter().map( | round | round.me ).collect();
+}
+
+// How one player got on in a tournament. Points are the round scores
+// added up over every match; wins, draws and losses count matches.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Standing
+{
+	pub name: String,
+	pub points: i64,
+	pub wins: usize,
+	pub draws: usize,
+	pub losses: usize,
+}
+
+// Every player plays every other player once. A match lasts as long as
+// the longer of the two strategies, with the shorter one starting again
+// from the top when it runs out. Standings come back best first: most
+// match wins, then most points, then in the order the players were given.
+pub fn tournament( game: &Game, players: &[ ( String, Vec< usize > ) ] ) -> Vec< Standing >
+{
+	let mut standings: Vec< Standing > = players.iter()
+		.map( | ( name, _ ) | Standing { name: name.clone(), points: 0, wins: 0, draws: 0, losses: 0 } )
+		.collect();
+
+	for a in 0..players.len()
+	{
+		for b in a + 1..players.len()
+		{
+			let ( a_plays, b_plays ) = ( &players[ a ].1, &players[ b ].1 );
+			let rounds = if a_plays.len() == 0 || b_plays.len() == 0 { 0 } else { a_plays.len().max( b_plays.len() ) };
+
+			let ( mut a_total, mut b_total ) = ( 0, 0 );
+			for round in 0..rounds
+			{
+				let ( a_play, b_play ) = ( a_plays[ round % a_plays.len() ], b_plays[ round % b_plays.len() ] );
+				a_total += ComputeScore( game, a_play, b_play ) as i64;
+				b_total += ComputeScore( game, b_play, a_play ) as i64;
+			}
+
+			standings[ a ].points += a_total;
+			standings[ b ].points += b_total;
+			if a_total > b_total
+			{
+				standings[ a ].wins += 1;
+				standings[ b ].losses += 1;
+			}
+			else if b_total > a_total
+			{
+				standings[ b ].wins += 1;
+				standings[ a ].losses += 1;
+			}
+			else
+			{
+				standings[ a ].draws += 1;
+				standings[ b ].draws += 1;
+			}
+		}
+	}
+
+	standings.sort_by_key( | s | Reverse( ( s.wins, s.points ) ) );
+	return standings;
+}
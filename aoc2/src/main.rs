@@ -1,149 +1,150 @@
-#![allow(non_snake_case)]
-use std::io::{ self, BufRead };
+use std::env;
+use std::process;
+use aoc2::Game;
+use aoc2::Guide;
 
+const USAGE: &str = "usage: aoc2 [--rounds] [--optimize] [--rules path] [--tournament guide...]";
 
-#[derive(Eq,PartialEq,Copy,Clone)]
-enum EPlay
+struct Args
 {
-	Rock,
-	Paper,
-	Scissors,
+	show_rounds: bool,
+	optimize: bool,
+	rules: Option< String >,
+	tournament: Vec< String >,
 }
 
-#[derive(Eq,PartialEq,Copy,Clone)]
-enum EResult
+fn parse_args() -> Result< Args, String >
 {
-	Win,
-	Lose,
-	Draw,
-}
+	let mut args = env::args().skip( 1 );
 
-fn ParsePlay( c: char ) -> EPlay
-{
-	match c 
+	let mut show_rounds = false;
+	let mut optimize = false;
+	let mut rules: Option< String > = None;
+	let mut tournament: Vec< String > = Vec::new();
+
+	while let Some( arg ) = args.next()
 	{
-		'A' => return EPlay::Rock,
-		'B' => return EPlay::Paper,
-		'C' => return EPlay::Scissors,
-		'X' => return EPlay::Rock,
-		'Y' => return EPlay::Paper,
-		'Z' => return EPlay::Scissors,
-		_ => panic!( "Invalid input {}", c )
+		match arg.as_str()
+		{
+			"--rounds" => show_rounds = true,
+			"--optimize" => optimize = true,
+			"--rules" =>
+			{
+				rules = Some( args.next().ok_or( "--rules needs a path" )? );
+			},
+			"--tournament" =>
+			{
+				// everything after this is a guide
+				tournament.extend( args.by_ref() );
+				if tournament.len() < 2
+				{
+					return Err( "--tournament needs at least two guides".to_string() );
+				}
+			},
+			_ => return Err( format!( "unexpected argument {}", arg ) ),
+		}
 	}
-}		
 
-fn ParseResult( c: char ) -> EResult
+	return Ok( Args { show_rounds, optimize, rules, tournament } );
+}
+
+fn run_tournament( game: &Game, paths: &[ String ] )
 {
-	match c 
+	let mut players: Vec< ( String, Vec< usize > ) > = Vec::new();
+	for path in paths
 	{
-		'X' => return EResult::Lose,
-		'Y' => return EResult::Draw,
-		'Z' => return EResult::Win,
-		_ => panic!( "Invalid input {}", c )
+		let guide = aoc_common::parse_file( path, | input | aoc2::parse_with( game.clone(), input ) );
+		match aoc2::plays( &guide )
+		{
+			Some( plays ) => players.push( ( path.clone(), plays ) ),
+			None =>
+			{
+				eprintln!( "{}: every second column letter has to be a move", path );
+				process::exit( 1 );
+			}
+		}
 	}
-}		
 
-
-fn ComputePlayScore( play: EPlay ) -> i32
-{
-	match play
+	println!( "{:<30}  {:>4}  {:>5}  {:>6}  {:>8}", "guide", "wins", "draws", "losses", "points" );
+	for standing in aoc2::tournament( game, &players )
 	{
-		EPlay::Rock => return 1,	
-		EPlay::Paper => return 2,	
-		EPlay::Scissors => return 3,	
+		println!( "{:<30}  {:>4}  {:>5}  {:>6}  {:>8}", standing.name, standing.wins, standing.draws, standing.losses, standing.points );
 	}
 }
 
-fn ComputeResultScore( result: EResult ) -> i32
+fn print_optimization( guide: &Guide )
 {
-	match result
+	let game = &guide.game;
+	let rounds = guide.rounds.len();
+
+	println!( "Elf's moves:" );
+	for ( play, count ) in aoc2::opponent_counts( guide ).iter().enumerate()
 	{
-		EResult::Win => return 6,	
-		EResult::Lose => return 0,	
-		EResult::Draw => return 3,	
+		let share = if rounds == 0 { 0.0 } else { *count as f64 * 100.0 / rounds as f64 };
+		println!( "  {:<10} {:>6}  {:>5.1}%", game.moves[ play ].name, count, share );
 	}
-}
 
+	let ( fixed, fixed_total ) = aoc2::best_fixed_response( guide );
+	let per_round = if rounds == 0 { 0.0 } else { fixed_total as f64 / rounds as f64 };
+	println!( "Best fixed response: {} every round, {} total, {:.2} per round", game.moves[ fixed ].name, fixed_total, per_round );
 
-fn ABeatsB( a: EPlay, b: EPlay ) -> bool
+	let best: i32 = guide.rounds.iter().zip( aoc2::best_responses( guide ) )
+		.map( | ( round, play ) | aoc2::ComputeScore( game, play, round.them ) )
+		.sum();
+	println!( "Best response each round: {} total", best );
+}
+
+fn show( score: Option< i32 > ) -> String
 {
-	if a == EPlay::Rock && b == EPlay::Scissors
-	{
-		return true;
-	}
-	else if a == EPlay::Scissors && b == EPlay::Paper
-	{
-		return true;
-	}
-	else if a == EPlay::Paper && b == EPlay::Rock
+	return match score
 	{
-		return true;
-	}
-	return false;
+		Some( score ) => score.to_string(),
+		None => "-".to_string(),
+	};
 }
 
-fn PlayForResult( them: EPlay, result: EResult ) -> EPlay
+fn main()
 {
-	if result == EResult::Draw
+	let args = match parse_args()
 	{
-		return them;
-	}
-	else if result == EResult::Win
-	{
-		match them
-		{
-			EPlay::Rock => return EPlay::Paper,
-			EPlay::Paper => return EPlay::Scissors,
-			EPlay::Scissors => return EPlay::Rock,
-		}
-	}
-	else
-	{
-		match them
+		Ok( args ) => args,
+		Err( e ) =>
 		{
-			EPlay::Rock => return EPlay::Scissors,
-			EPlay::Paper => return EPlay::Rock,
-			EPlay::Scissors => return EPlay::Paper,
+			eprintln!( "{}\n{}", e, USAGE );
+			process::exit( 2 );
 		}
-	}
-}
+	};
 
-
-fn ComputeScore( me: EPlay, them: EPlay ) -> i32
-{
-	let mut score = ComputePlayScore( me );
-	if me == them
+	let game = match &args.rules
 	{
-		score += 3;
-	}
-	else if ABeatsB( me, them )
+		Some( path ) => aoc_common::parse_file( path, Game::parse ),
+		None => Game::standard(),
+	};
+
+	if args.tournament.len() != 0
 	{
-		score += 6;
+		run_tournament( &game, &args.tournament );
+		return;
 	}
 
-	return score;
-}
-
-fn main()
-{
-	let mut lines = io::stdin().lock().lines();
+	let guide = aoc_common::parse_stdin( | input | aoc2::parse_with( game, input ) );
+	let scores = aoc2::score_rounds( &guide );
 
-	let mut total = 0;
-	while let Some( line ) = lines.next()
+	if args.show_rounds
 	{
-		let curLine = line.unwrap();
-		if curLine.len() < 3
+		println!( "round  guide  as play  as result" );
+		for ( i, ( round, score ) ) in guide.rounds.iter().zip( &scores ).enumerate()
 		{
-			break;
+			println!( "{:>5}  {:>5}  {:>7}  {:>9}", i + 1, round, show( score.as_play ), show( score.as_result ) );
 		}
+	}
+
+	let total = aoc2::totals( &scores );
+	println!( "Total playing the guide: {}", show( total.as_play ) );
+	println!( "Total: {}", show( total.as_result ) );
 
-		let them = ParsePlay( curLine.chars().nth( 0 ).unwrap() );
-		let result = ParseResult( curLine.chars().nth( 2 ).unwrap() );
-		let me = PlayForResult( them, result );
-		let score = ComputeResultScore( result ) + ComputePlayScore( me );
-		total += score;
-		//println!( "Score: {}", score );
+	if args.optimize
+	{
+		print_optimization( &guide );
 	}
-	println!( "Total: {}", total );
 }
-
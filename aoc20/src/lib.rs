@@ -0,0 +1,137 @@
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+
+#[derive(Clone,Copy,Debug)]
+struct Num
+{
+	id: usize,
+	number: i64,
+}
+
+
+pub fn parse( input: &str ) -> Result< Vec< i64 >, ParseError >
+{
+	let mut values: Vec< i64 > = Vec::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		values.push( scanner.number::<i64>()? );
+		scanner.end()?;
+	}
+
+	if values.len() == 0
+	{
+		return Err( ParseError::new( 1, 1, "a number" ) );
+	}
+
+	return Ok( values );
+}
+
+// Part 1 mixes the plain values once. Part 2 applies the decryption key
+// first and mixes ten times.
+const DECRYPTION_KEY: i64 = 811589153;
+const DECRYPTED_MIX_COUNT: usize = 10;
+
+fn grove_coordinates( values: &[ i64 ], mult: i64, mix_count: usize ) -> i64
+{
+	let mut numbers: Vec< Num > = Vec::new();
+
+	for value in values
+	{
+		numbers.push( Num{ id: numbers.len(), number: mult * value } );
+	}
+
+	for _ in 0..mix_count
+	{
+		for i in 0..numbers.len()
+		{
+			// find the number that matches i, wherever it currently is
+			let mut ind = 0;
+			for test in 0..numbers.len()
+			{
+				if numbers[test].id == i
+				{
+					ind = test;
+					break;
+				}
+			}
+
+			if numbers[ ind ].number != 0
+			{
+				let to_move = numbers[ind].number;
+				let moving = numbers.remove( ind );
+				let mut insert_before = ind as i64;
+
+				//println!( "");
+
+				// first add how many positions it wants to move
+				insert_before += to_move;
+
+				// then make it positive if it isn't
+				let len = numbers.len() as i64;
+				if insert_before < 0
+				{
+					let n = insert_before.abs() / len;
+					//println!( "insert_before={}, n={}", insert_before, n );
+					insert_before += ( n + 1 ) * len;
+				}
+
+				// then make it in the right range
+				insert_before = insert_before % len;
+
+				/*
+				println!( "i={}, ind={}, insert_before={}", i, ind, insert_before );
+				let prev = ( (insert_before + len  - 1 ) % len ) as usize;
+				let next = insert_before as usize;
+				println!( "{} moves between {} and {}", to_move,
+					numbers[ prev ].number,
+					numbers[ next ].number,
+				);
+				*/
+
+				if insert_before == 0
+				{
+					numbers.push( moving );
+				}
+				else
+				{
+					numbers.insert( insert_before as usize, moving );
+				}
+				//dump_numbers( &numbers );
+			}
+			else
+			{
+				//println!( "\n0 does not move" );
+				//dump_numbers( &numbers );
+			}
+		}
+	}
+
+
+	let mut ind = 0;
+	for test in 0..numbers.len()
+	{
+		if numbers[test].number == 0
+		{
+			ind = test;
+			break;
+		}
+	}
+
+	let a = numbers[ (ind + 1000 ) % numbers.len() ].number;
+	let b = numbers[ (ind + 2000 ) % numbers.len() ].number;
+	let c = numbers[ (ind + 3000 ) % numbers.len() ].number;
+	return a + b + c;
+}
+
+pub fn part1( values: &[ i64 ] ) -> Option< i64 >
+{
+	return Some( grove_coordinates( values, 1, 1 ) );
+}
+
+pub fn part2( values: &[ i64 ] ) -> Option< i64 >
+{
+	return Some( grove_coordinates( values, DECRYPTION_KEY, DECRYPTED_MIX_COUNT ) );
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+
+#[derive(Clone,Debug)]
+pub enum Op 
+{
+	Number( i64 ),
+	Add( String, String ),
+	Subtract( String, String),
+	Multiply( String, String),
+	Divide( String, String),
+	Human,
+}
+
+#[derive(Clone,Copy,Debug)]
+enum SimpleOp 
+{
+	Add,
+	Subtract,
+	SubtractReverse,
+	Multiply,
+	Divide,
+	DivideReverse,
+}
+
+fn match_pair( op: &Op ) -> ( String, String )
+{
+	
+	match op
+	{
+		Op::Add( a, b ) 
+		| Op::Subtract( a, b ) 
+		| Op::Multiply( a, b )
+		| Op::Divide( a, b ) 
+		=> 
+		{ 
+			return ( a.clone(), b.clone() );
+		}
+		_ => panic!( "match_pair can only be called on add/subtract/multiply/divide ops: {:?}", op ),
+	}
+}
+
+fn run_op( monkey_name: &String, monkeys: &HashMap< String, Op > ) -> Option< i64 >
+{
+	let op = monkeys.get( monkey_name ).unwrap();
+
+	let a_value: Option< i64 >;
+	let b_value: Option< i64 >;
+
+	match op
+	{
+		Op::Human => return None,
+		Op::Number( n ) => return Some( *n ),
+		_ => {},
+	}
+	
+	let ( a, b ) = match_pair( op );
+	a_value = run_op( &a, monkeys );
+	b_value = run_op( &b, monkeys );
+	if a_value == Option::None || b_value == Option::None
+	{
+		return Option::None;
+	}
+
+	let a_value = a_value.unwrap();
+	let b_value = b_value.unwrap();
+
+	return match op
+	{
+		Op::Add( _, _ ) => Some( a_value + b_value ),
+		Op::Subtract( _, _ ) => Some( a_value - b_value ),
+		Op::Multiply( _, _ ) => Some( a_value * b_value ),
+		Op::Divide( _, _ ) => Some( a_value / b_value ),
+		_ => panic!("How did we get here? {:?}", op ),
+	};
+}
+
+fn compute_unknown_value( result: i64, known: i64, op: SimpleOp ) -> i64
+{
+	return match op
+	{
+		SimpleOp::Add => result - known, 			// result = known + human
+		SimpleOp::Subtract => known - result, 		// result = known - human
+		SimpleOp::SubtractReverse => result + known,// result = human - known
+		SimpleOp::Multiply => result / known, 		// result = known * human
+		SimpleOp::Divide => known / result,			// result = known / human
+		SimpleOp::DivideReverse => known * result, 	// result = human / known
+	};
+}
+
+
+fn make_human_equal( result: i64, known: i64, op: SimpleOp, human_branch: &String, monkeys: &HashMap< String, Op > ) -> i64
+{
+	let unknown_value = compute_unknown_value( result, known, op );
+	let child_op = monkeys.get( human_branch ).unwrap();
+
+	let simple_op: SimpleOp = match child_op
+	{
+		Op::Human =>
+		{
+			return unknown_value;
+		},
+		Op::Number(_) => panic!( "A simple number monkey shouldn't be on the unresolved side" ),
+		Op::Add(_,_) => SimpleOp::Add,
+		Op::Subtract(_,_) => SimpleOp::Subtract,
+		Op::Multiply(_,_) => SimpleOp::Multiply,
+		Op::Divide(_,_) => SimpleOp::Divide,
+	};
+
+	let ( a, b ) = match_pair( child_op );
+
+	let a_value = run_op( &a, monkeys );
+	let b_value = run_op( &b, monkeys );
+
+	return if a_value == Option::None
+	{
+		let simple_op = match simple_op
+		{
+			SimpleOp::Subtract => SimpleOp::SubtractReverse,
+			SimpleOp::Divide => SimpleOp::DivideReverse,
+			_ => simple_op,
+		};
+
+		make_human_equal( unknown_value, b_value.unwrap(), simple_op, &a, monkeys )
+	}
+	else
+	{
+		make_human_equal( unknown_value, a_value.unwrap(), simple_op, &b, monkeys )
+	};
+}
+
+
+pub fn parse( input: &str ) -> Result< HashMap< String, Op >, ParseError >
+{
+	let mut monkeys: HashMap< String, Op > = HashMap::new();
+
+	// line, column and name of every monkey that gets listened to
+	let mut references: Vec< ( usize, usize, String ) > = Vec::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		let monkey_name = scanner.word()?;
+		scanner.literal( ": " )?;
+
+		let op = if scanner.rest().starts_with( | c: char | c.is_ascii_digit() || c == '-' )
+		{
+			Op::Number( scanner.number::<i64>()? )
+		}
+		else
+		{
+			let column = scanner.column();
+			let a = scanner.word()?.to_string();
+			references.push( ( i + 1, column, a.clone() ) );
+
+			let op = scanner.one_of( &[ " + ", " - ", " * ", " / " ] )?;
+
+			let column = scanner.column();
+			let b = scanner.word()?.to_string();
+			references.push( ( i + 1, column, b.clone() ) );
+
+			match op
+			{
+				0 => Op::Add( a, b ),
+				1 => Op::Subtract( a, b ),
+				2 => Op::Multiply( a, b ),
+				_ => Op::Divide( a, b ),
+			}
+		};
+		scanner.end()?;
+
+		monkeys.insert( monkey_name.to_string(), op );
+	}
+
+	for ( line, column, name ) in references
+	{
+		if !monkeys.contains_key( &name )
+		{
+			return Err( ParseError::new( line, column, "the name of a monkey in the input" ) );
+		}
+	}
+	for name in [ "root", "humn" ]
+	{
+		if !monkeys.contains_key( name )
+		{
+			return Err( ParseError::new( input.lines().count() + 1, 1, &format!( "a monkey named {}", name ) ) );
+		}
+	}
+
+	return Ok( monkeys );
+}
+
+pub fn part1( monkeys: &HashMap< String, Op > ) -> Option< i64 >
+{
+	return run_op( &"root".to_string(), monkeys );
+}
+
+pub fn part2( monkeys: &HashMap< String, Op > ) -> Option< i64 >
+{
+	let mut monkeys = monkeys.clone();
+	monkeys.insert( "humn".to_string(), Op::Human );
+
+	let root_op = monkeys.get( "root" ).unwrap();
+	let ( a, b ) = match_pair( root_op );
+
+	let a_value = run_op( &a, &monkeys );
+	let b_value = run_op( &b, &monkeys );
+
+	let human_value = if a_value == Option::None
+	{
+		make_human_equal( b_value.unwrap(), 0, SimpleOp::Add, &a, &monkeys )
+	}
+	else
+	{
+		make_human_equal( a_value.unwrap(), 0, SimpleOp::Add, &b, &monkeys )
+	};
+
+	return Some( human_value );
+}
+
@@ -0,0 +1,251 @@
+use std::cmp;
+use std::collections::HashSet;
+use std::collections::HashMap;
+use aoc_common::ParseError;
+
+
+type Pt = ( i32, i32 );
+
+#[derive(Clone,Copy,Debug)]
+struct Elf
+{
+	pos: Pt,
+	proposed_pos: Option< Pt >,
+}
+
+impl Elf
+{
+	fn new( pos: Pt ) -> Elf
+	{
+		return Elf
+		{
+			pos,
+			proposed_pos: Option::None,
+		};
+	}
+}
+
+
+#[derive(Clone,Copy,Debug)]
+struct Bounds
+{
+	top: i32,
+	bottom: i32,
+	left: i32,
+	right: i32,
+}
+
+type ElfVec = Vec< Elf >;
+fn elf_bounds( elves: &ElfVec ) -> Bounds
+{
+	let mut top: i32 = i32::MAX;
+	let mut left: i32 = i32::MAX;
+	let mut bottom: i32 = i32::MIN;
+	let mut right: i32 = i32::MIN;
+
+	for elf in elves
+	{
+		top = cmp::min( elf.pos.1, top );
+		bottom = cmp::max( elf.pos.1, bottom );
+		left = cmp::min( elf.pos.0, left );
+		right = cmp::max( elf.pos.0, right );
+	}
+
+	return Bounds { top, bottom, left, right };
+}
+
+fn elf_occupied( elves: &ElfVec ) -> HashSet< Pt >
+{
+	let mut occupied: HashSet< Pt > = HashSet::new();
+	for elf in elves
+	{
+	 	occupied.insert( elf.pos );	
+	}
+
+	return occupied;
+}
+
+#[allow(dead_code)]
+fn dump_elves( elves: &ElfVec )
+{
+	let bounds = elf_bounds( elves );
+	let occupied = elf_occupied( elves );
+
+	for y in bounds.top..( bounds.bottom + 1 )
+	{
+		let y = y as i32;
+		let mut line = String::new();
+
+		for x in bounds.left..( bounds.right + 1 )
+		{
+			let x = x as i32;
+			line.push( if occupied.contains( &( x, y ) ) { '#' } else { '.' } );
+		}
+
+		println!( "{}", line );
+	}
+}
+
+
+fn elf_proposal( elf : &Elf, occupied: &HashSet< Pt >, round: usize ) -> Option< Pt >
+{
+	let ( x, y ) = elf.pos;
+
+	let nw = occupied.contains( &( x - 1, y - 1 ) ); 
+	let n = occupied.contains( &( x, y - 1 ) ); 
+	let ne = occupied.contains( &( x + 1, y - 1 ) ); 
+
+	let sw = occupied.contains( &( x - 1, y + 1 ) ); 
+	let s = occupied.contains( &( x, y + 1 ) ); 
+	let se = occupied.contains( &( x + 1, y + 1 ) ); 
+
+	let e = occupied.contains( &( x + 1, y ) ); 
+	let w = occupied.contains( &( x - 1, y ) ); 
+
+	// if nothing is occupied, the elf won't move
+	if !nw && !n && !ne && !e && !w && !sw && !s && !se
+	{
+		return Option::None;
+	}
+
+	let order = vec![
+		( !nw && !n && !ne, Option::Some( ( x, y - 1 ) ) ),
+		( !sw && !s && !se, Option::Some( ( x, y + 1 ) ) ),
+		( !nw && !w && !sw, Option::Some( ( x - 1, y ) ) ),
+		( !ne && !e && !se, Option::Some( ( x + 1, y ) ) ),
+	];
+
+
+	for n in 0..4
+	{
+		let n = ( n + round ) % 4;
+		let ( test, res ) = order[ n ];
+		if test
+		{
+			return res;
+		}
+	}
+
+	return Option::None;
+}
+
+
+pub fn parse( input: &str ) -> Result< Vec< ( i32, i32 ) >, ParseError >
+{
+	let mut positions: Vec< Pt > = Vec::new();
+
+	for ( y, cur_line ) in input.lines().enumerate()
+	{
+		for ( x, c ) in cur_line.char_indices()
+		{
+			match c
+			{
+				'#' => positions.push( ( x as i32, y as i32 ) ),
+				'.' => {},
+				_ => return Err( ParseError::new( y + 1, x + 1, "`.` or `#`" ) ),
+			}
+		}
+	}
+
+	return Ok( positions );
+}
+
+fn make_elves( positions: &[ ( i32, i32 ) ] ) -> ElfVec
+{
+	let mut elves = ElfVec::new();
+	for pos in positions
+	{
+		elves.push( Elf::new( *pos ) );
+	}
+	return elves;
+}
+
+// Run one round of proposals and moves, returning how many elves moved
+fn elf_round( elves: &mut ElfVec, round: usize ) -> usize
+{
+	let occupied = elf_occupied( elves );
+	let mut proposals = HashMap::<Pt, u32>::new();
+	
+	// make all the proposals
+	for elf in elves.iter_mut()
+	{
+		elf.proposed_pos = elf_proposal( elf, &occupied, round );
+		match elf.proposed_pos
+		{
+			Option::None => {},
+			Option::Some( pt ) =>
+			{
+				match proposals.get_mut( &pt )
+				{
+					Option::None => { proposals.insert( pt, 1 ); },
+					Option::Some( old_count ) => *old_count += 1,
+				}
+			},
+		}
+	}
+
+	// execute all the proposals
+	let mut elves_moved = 0;
+	for elf in elves.iter_mut()
+	{
+		match elf.proposed_pos
+		{
+			Option::None => {},
+			Option::Some( dest ) =>
+			{
+				match proposals.get( &dest )
+				{
+					Option::Some( 1 ) =>
+					{
+						// exactly one elf proposed moving here
+						elf.pos = dest;
+						elves_moved += 1;
+					},
+					_ => {}, // otherwise do nothing
+				}
+			}
+		}
+	}
+
+	return elves_moved;
+}
+
+// empty ground tiles in the smallest rectangle around the elves after 10 rounds
+pub fn part1( positions: &[ ( i32, i32 ) ] ) -> Option< usize >
+{
+	let mut elves = make_elves( positions );
+	if elves.len() == 0
+	{
+		return None;
+	}
+
+	for round in 0..10
+	{
+		elf_round( &mut elves, round );
+	}
+
+	let bounds = elf_bounds( &elves );
+	let area = ( bounds.right - bounds.left + 1 ) as usize * ( bounds.bottom - bounds.top + 1 ) as usize;
+	return Some( area - elves.len() );
+}
+
+pub fn part2( positions: &[ ( i32, i32 ) ] ) -> Option< usize >
+{
+	let mut elves = make_elves( positions );
+
+	let mut round: usize = 0;
+	loop 
+	{
+		if elf_round( &mut elves, round ) == 0
+		{
+			break;
+		}
+
+		//dump_elves( &elves );
+		//println!( "" );
+
+		round += 1;
+	}
+
+	return Some( round + 1 );
+}
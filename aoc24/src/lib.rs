@@ -0,0 +1,360 @@
+use aoc_common::Grid;
+use aoc_common::ParseError;
+use aoc_common::search;
+use aoc_common::search::SearchSpace;
+
+
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+enum Dir
+{
+	Right = 0,
+	Down = 1,
+	Left = 2,
+	Up = 3,
+}
+
+
+type Pt=(usize, usize );
+
+// Where the party is. It can wait outside the valley for as long as it
+// likes before stepping in.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+enum Spot
+{
+	Outside,
+	In( Pt ),
+	Arrived,
+}
+
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+struct Move
+{
+	spot: Spot,
+	// The round when the party arrived here, modulo the blizzard period.
+	// Being somewhere at the same point in the cycle again never helps.
+	phase: usize,
+}
+
+type BlizzardVec= Vec<usize>;
+
+#[derive(Clone,Debug)]
+pub struct Weather
+{
+	down: Vec<BlizzardVec>,
+	up: Vec<BlizzardVec>,
+	right: Vec<BlizzardVec>,
+	left: Vec<BlizzardVec>,
+	width: usize,
+	height: usize,
+}
+
+
+impl Weather
+{
+	fn new( width: usize, height: usize ) -> Weather
+	{
+		let mut weather = Weather
+		{
+			width, height,
+			down: Vec::new(),
+			up: Vec::new(),
+			right: Vec::new(),
+			left: Vec::new(),
+		};
+
+		weather.down.resize( width, Vec::new() );
+		weather.up.resize( width, Vec::new() );
+		weather.right.resize( height, Vec::new() );
+		weather.left.resize( height, Vec::new() );
+
+		return weather;
+	}
+
+	fn blocked( self: &Self, pt: &Pt, round: usize ) -> bool
+	{
+		return self.blizzard_dir( pt, round ).len() > 0;
+	}
+
+	fn blizzard_dir( self: &Self, pt: &Pt, round: usize ) -> Vec< Dir >
+	{
+		let ( x, y ) = *pt;
+
+		let mut res = Vec::<Dir>::new();
+		for bliz in &self.down[ x ]
+		{
+			// see if this blizzard is at Y at this time
+			let bliz_y = ( bliz + round ) % self.height;
+			if bliz_y == y
+			{
+				res.push( Dir::Down );
+			}
+		}
+
+		for bliz in &self.up[ x ]
+		{
+			// see if this blizzard is at Y at this time
+			let bliz_y = ( bliz + self.height - round % self.height ) % self.height;
+			if bliz_y == y
+			{
+				res.push( Dir::Up );
+			}
+		}
+
+		for bliz in &self.right[ y ]
+		{
+			// see if this blizzard is at X at this time
+			let bliz_x = ( bliz + round ) % self.width;
+			if bliz_x == x
+			{
+				res.push( Dir::Right );
+			}
+		}
+
+		for bliz in &self.left[ y ]
+		{
+			// see if this blizzard is at X at this time
+			let bliz_x = ( bliz + self.width - round % self.width ) % self.width;
+			if bliz_x == x
+			{
+				res.push( Dir::Left );
+			}
+		}
+
+		return res;
+	}
+
+	#[allow(dead_code)]
+	fn dump( self: &Self, round: usize )
+	{
+		let mut valley: Grid< char > = Grid::new( self.width + 2, self.height + 2, '#' );
+		valley[ ( 1, 0 ) ] = '.';
+		valley[ ( self.width, self.height + 1 ) ] = '.';
+
+		for y in 0..self.height
+		{
+			for x in 0..self.width
+			{
+				let pt = ( x, y );	
+				let blizzards = self.blizzard_dir( &pt, round );
+				let c = match blizzards.len()
+				{
+					0 => '.',
+					1 => match blizzards[0]
+					{
+						Dir::Up => '^',
+						Dir::Down => 'v',
+						Dir::Left => '<',
+						Dir::Right => '>',
+					},
+					n => char::from_digit( n as u32, 10 ).unwrap_or( '*' ),
+				};
+
+				valley[ ( x + 1, y + 1 ) ] = c;
+			}
+		}
+
+		println!( "{}", valley );
+	}
+
+	fn add_blizzard( self: &mut Self, dir: Dir, pt: &Pt )
+	{
+		let ( x, y ) = *pt;
+		match dir
+		{
+			Dir::Up => self.up[x].push( y ),
+			Dir::Down => self.down[x].push( y ),
+			Dir::Left => self.left[y].push( x ),
+			Dir::Right => self.right[y].push( x ),
+		}
+	}
+}
+
+
+pub fn parse( input: &str ) -> Result< Weather, ParseError >
+{
+	let valley = Grid::parse( input, "`.`, `>`, `<`, `^` or `v`", | _x, _y, c |
+	{
+		return match c
+		{
+			'#' | '.' | '>' | '<' | '^' | 'v' => Some( c ),
+			_ => None,
+		};
+	} )?;
+	if valley.height() < 3 || valley.width() < 3
+	{
+		return Err( ParseError::new( 1, 1, "a walled valley at least one square across" ) );
+	}
+
+	let width = valley.width() - 2;
+	let height = valley.height() - 2;
+
+	// the entrance is top left, the exit is bottom right
+	let top = format!( "#.{}", "#".repeat( width ) );
+	let bottom = format!( "{}.#", "#".repeat( width ) );
+	if valley.row( 0 ).iter().collect::< String >() != top
+	{
+		return Err( ParseError::new( 1, 1, &format!( "`{}`", top ) ) );
+	}
+	if valley.row( height + 1 ).iter().collect::< String >() != bottom
+	{
+		return Err( ParseError::new( height + 2, 1, &format!( "`{}`", bottom ) ) );
+	}
+
+	let mut weather = Weather::new( width, height );
+	for y in 0..height
+	{
+		for ( i, c ) in valley.row( y + 1 ).iter().enumerate()
+		{
+			if i == 0 || i == width+1
+			{
+				if *c != '#'
+				{
+					return Err( ParseError::new( y + 2, i + 1, "`#`" ) );
+				}
+				continue;
+			}
+
+			let x = i - 1;
+			let pt = ( x, y );
+			match c
+			{
+				'.' => {}, // empty space
+				'>' => weather.add_blizzard( Dir::Right, &pt ),
+				'<' => weather.add_blizzard( Dir::Left, &pt ),
+				'^' => weather.add_blizzard( Dir::Up, &pt ),
+				'v' => weather.add_blizzard( Dir::Down, &pt ),
+				_ => return Err( ParseError::new( y + 2, i + 1, "`.`, `>`, `<`, `^` or `v`" ) ),
+			}
+		}
+	}	
+
+	return Ok( weather );
+}
+
+// One trip across the valley from the square next to the way in at
+// `start` to the one next to the way out at `goal`
+struct Crossing< 'a >
+{
+	weather: &'a Weather,
+	start: Pt,
+	goal: Pt,
+	period: usize,
+}
+
+impl Crossing< '_ >
+{
+	fn step( self: &Self, pt: Pt, dir: Dir ) -> Option< Pt >
+	{
+		let ( x, y ) = pt;
+		return match dir
+		{
+			Dir::Up if y > 0 => Some( ( x, y - 1 ) ),
+			Dir::Down if y < self.weather.height - 1 => Some( ( x, y + 1 ) ),
+			Dir::Left if x > 0 => Some( ( x - 1, y ) ),
+			Dir::Right if x < self.weather.width - 1 => Some( ( x + 1, y ) ),
+			_ => None,
+		};
+	}
+}
+
+impl SearchSpace for Crossing< '_ >
+{
+	type State = Move;
+
+	fn successors( self: &Self, curr: &Move ) -> Vec< ( Move, usize ) >
+	{
+		let phase = ( curr.phase + 1 ) % self.period;
+
+		let mut spots: Vec< Spot > = Vec::new();
+		match curr.spot
+		{
+			Spot::Outside =>
+			{
+				// we can always wait outside
+				spots.push( Spot::Outside );
+				spots.push( Spot::In( self.start ) );
+			},
+			Spot::In( pt ) =>
+			{
+				if pt == self.goal
+				{
+					spots.push( Spot::Arrived );
+				}
+
+				// we can wait in place if nothing blows in
+				spots.push( Spot::In( pt ) );
+				for dir in [ Dir::Right, Dir::Down, Dir::Left, Dir::Up ]
+				{
+					if let Some( next ) = self.step( pt, dir )
+					{
+						spots.push( Spot::In( next ) );
+					}
+				}
+			},
+			Spot::Arrived => {},
+		}
+
+		let mut moves: Vec< ( Move, usize ) > = Vec::new();
+		for spot in spots
+		{
+			// see if the party would immediately get murdered
+			if let Spot::In( pt ) = spot
+			{
+				if self.weather.blocked( &pt, phase )
+				{
+					//oops. Dead elves
+					continue;
+				}
+			}
+			moves.push( ( Move { spot, phase }, 1 ) );
+		}
+
+		return moves;
+	}
+
+	fn is_goal( self: &Self, curr: &Move ) -> bool
+	{
+		return curr.spot == Spot::Arrived;
+	}
+}
+
+fn gcd( a: usize, b: usize ) -> usize
+{
+	return if b == 0 { a } else { gcd( b, a % b ) };
+}
+
+// Earliest round the party can step out of the valley past `goal`, having
+// been waiting next to `start` at round `depart`
+fn crossing( weather: &Weather, start: Pt, goal: Pt, depart: usize ) -> Option< usize >
+{
+	// the blizzards are back where they started after this many rounds
+	let period = weather.width * weather.height / gcd( weather.width, weather.height );
+
+	let space = Crossing { weather, start, goal, period };
+	let result = search::bfs( &space, Move { spot: Spot::Outside, phase: depart % period } );
+
+	// every step takes a round
+	return result.path.map( | path | depart + path.cost );
+}
+
+// The entrance is above the top left square, the exit below the bottom
+// right one
+fn corners( weather: &Weather ) -> ( Pt, Pt )
+{
+	return ( ( 0, 0 ), ( weather.width - 1, weather.height - 1 ) );
+}
+
+pub fn part1( weather: &Weather ) -> Option< usize >
+{
+	let ( entrance, exit ) = corners( weather );
+	return crossing( weather, entrance, exit, 0 );
+}
+
+// there, back for the snacks, and there again
+pub fn part2( weather: &Weather ) -> Option< usize >
+{
+	let ( entrance, exit ) = corners( weather );
+	let there = crossing( weather, entrance, exit, 0 )?;
+	let back = crossing( weather, exit, entrance, there )?;
+	return crossing( weather, entrance, exit, back );
+}
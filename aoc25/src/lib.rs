@@ -0,0 +1,155 @@
+use std::fmt;
+use std::str::FromStr;
+use aoc_common::ParseError;
+
+// A number written in SNAFU, balanced base 5: each place is worth five
+// times the one to its right, and the digits are 2, 1, 0, - (minus one)
+// and = (minus two)
+#[derive(Eq,PartialEq,Ord,PartialOrd,Clone,Copy,Hash,Debug)]
+pub struct Snafu
+{
+	pub value: i128,
+}
+
+const DIGITS: &str = "a SNAFU digit (`2`, `1`, `0`, `-` or `=`)";
+
+fn digit_value( c: char ) -> Option< i128 >
+{
+	return match c
+	{
+		'2' => Some( 2 ),
+		'1' => Some( 1 ),
+		'0' => Some( 0 ),
+		'-' => Some( -1 ),
+		'=' => Some( -2 ),
+		_ => None,
+	};
+}
+
+impl From< i64 > for Snafu
+{
+	fn from( value: i64 ) -> Snafu
+	{
+		return Snafu { value: value as i128 };
+	}
+}
+
+impl From< i128 > for Snafu
+{
+	fn from( value: i128 ) -> Snafu
+	{
+		return Snafu { value };
+	}
+}
+
+impl TryFrom< Snafu > for i64
+{
+	type Error = std::num::TryFromIntError;
+
+	fn try_from( snafu: Snafu ) -> Result< i64, Self::Error >
+	{
+		return i64::try_from( snafu.value );
+	}
+}
+
+// Errors are reported on line 1; parse() moves them to the right line
+impl FromStr for Snafu
+{
+	type Err = ParseError;
+
+	fn from_str( s: &str ) -> Result< Snafu, ParseError >
+	{
+		if s.len() == 0
+		{
+			return Err( ParseError::new( 1, 1, "a SNAFU number" ) );
+		}
+
+		let mut value: i128 = 0;
+		for ( x, c ) in s.chars().enumerate()
+		{
+			let digit = match digit_value( c )
+			{
+				Some( digit ) => digit,
+				None => return Err( ParseError::new( 1, x + 1, DIGITS ) ),
+			};
+
+			// value * 5 + digit, but adding the digit before the last lot of
+			// value so numbers right at the ends of the range don't overflow
+			// on the way there
+			value = match value.checked_mul( 4 ).and_then( | v | v.checked_add( digit ) ).and_then( | v | v.checked_add( value ) )
+			{
+				Some( value ) => value,
+				None => return Err( ParseError::new( 1, 1, "a SNAFU number that fits in 128 bits" ) ),
+			};
+		}
+
+		return Ok( Snafu { value } );
+	}
+}
+
+impl fmt::Display for Snafu
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		if self.value == 0
+		{
+			return f.write_str( "0" );
+		}
+
+		// Peel off the lowest place each time. A remainder of 3 or 4 is
+		// written as = or - with one carried into the next place up.
+		let mut digits: Vec< char > = Vec::new();
+		let mut n = self.value;
+		while n != 0
+		{
+			let ( digit, carry ) = match n.rem_euclid( 5 )
+			{
+				0 => ( '0', 0 ),
+				1 => ( '1', 0 ),
+				2 => ( '2', 0 ),
+				3 => ( '=', 1 ),
+				_ => ( '-', 1 ),
+			};
+			digits.push( digit );
+			n = n.div_euclid( 5 ) + carry;
+		}
+
+		let out: String = digits.iter().rev().collect();
+		f.write_str( &out )
+	}
+}
+
+pub fn parse( input: &str ) -> Result< Vec< Snafu >, ParseError >
+{
+	let mut numbers: Vec< Snafu > = Vec::new();
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		match cur_line.parse::< Snafu >()
+		{
+			Ok( number ) => numbers.push( number ),
+			Err( e ) => return Err( ParseError::new( i + 1, e.column, &e.expected ) ),
+		}
+	}
+
+	return Ok( numbers );
+}
+
+// The fuel requirements added up, in SNAFU for Bob's console. None if the
+// sum won't fit.
+pub fn part1( numbers: &[ Snafu ] ) -> Option< Snafu >
+{
+	let mut sum: i128 = 0;
+	for n in numbers
+	{
+		sum = sum.checked_add( n.value )?;
+	}
+
+	return Some( Snafu::from( sum ) );
+}
+
+// Day 25 only has the one puzzle
+pub fn part2( _numbers: &[ Snafu ] ) -> Option< Snafu >
+{
+	return None;
+}
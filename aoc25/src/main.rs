@@ -0,0 +1,13 @@
+fn main()
+{
+	let numbers = aoc_common::parse_stdin( aoc25::parse );
+
+	if let Some( sum ) = aoc25::part1( &numbers )
+	{
+		println!( "fuel requirement: {}", sum );
+	}
+	if let Some( answer ) = aoc25::part2( &numbers )
+	{
+		println!( "part 2: {}", answer );
+	}
+}
@@ -0,0 +1,373 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+const DUPLICATE_ITEM: &str = "an item that isn't already in the table";
+
+// " 53" after an item in a priority table
+fn priority( scanner: &mut Scanner ) -> Result< i32, ParseError >
+{
+	scanner.literal( " " )?;
+	let priority = scanner.number::< i32 >()?;
+	scanner.end()?;
+	return Ok( priority );
+}
+
+// The items that can go in a rucksack and what each one is worth.
+// Rucksacks store items as indices into `items`, so there's no limit on
+// how many there are.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Alphabet
+{
+	items: Vec< ( char, i32 ) >,
+	index: HashMap< char, usize >,
+
+	// what to ask for when a rucksack holds something else
+	expected: String,
+}
+
+impl Alphabet
+{
+	// a to z are worth 1 to 26, A to Z 27 to 52
+	pub fn standard() -> Alphabet
+	{
+		let mut alphabet = Alphabet::from_items( "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ" ).unwrap();
+		alphabet.expected = "an item from a to z or A to Z".to_string();
+		return alphabet;
+	}
+
+	// Each item is worth its position in `items`, starting from 1. An item
+	// given twice is an error at the column of its second copy.
+	pub fn from_items( items: &str ) -> Result< Alphabet, ParseError >
+	{
+		let priorities: Vec< ( char, i32 ) > = items.chars().zip( 1.. ).collect();
+		return Alphabet::from_priorities( &priorities ).map_err( | e | ParseError::new( 1, e.line, &e.expected ) );
+	}
+
+	// An item given twice is an error on its second line, as if `items`
+	// were a priority table
+	pub fn from_priorities( items: &[ ( char, i32 ) ] ) -> Result< Alphabet, ParseError >
+	{
+		let mut index: HashMap< char, usize > = HashMap::new();
+		for ( i, ( c, _ ) ) in items.iter().enumerate()
+		{
+			if index.insert( *c, i ).is_some()
+			{
+				return Err( ParseError::new( i + 1, 1, DUPLICATE_ITEM ) );
+			}
+		}
+
+		let expected = if items.len() <= 10
+		{
+			let names: Vec< String > = items.iter().map( | ( c, _ ) | format!( "`{}`", c ) ).collect();
+			format!( "one of {}", names.join( ", " ) )
+		}
+		else
+		{
+			"an item in the alphabet".to_string()
+		};
+		return Ok( Alphabet { items: items.to_vec(), index, expected } );
+	}
+
+	// A priority table, one item per line followed by its priority:
+	//
+	//   a 1
+	//   é 53
+	//
+	// Columns count characters, not bytes, so they still line up after an
+	// item like é.
+	pub fn parse( table: &str ) -> Result< Alphabet, ParseError >
+	{
+		let mut items: Vec< ( char, i32 ) > = Vec::new();
+		for ( i, cur_line ) in table.lines().enumerate()
+		{
+			if cur_line.len() == 0
+			{
+				continue;
+			}
+
+			let item = cur_line.chars().next().unwrap();
+			if items.iter().any( | ( c, _ ) | *c == item )
+			{
+				return Err( ParseError::new( i + 1, 1, DUPLICATE_ITEM ) );
+			}
+
+			// the scanner counts bytes, so it starts after the item, which is
+			// always one column wide
+			let mut scanner = Scanner::new( i + 1, &cur_line[ item.len_utf8().. ] );
+			let priority = priority( &mut scanner ).map_err( | e | ParseError::new( e.line, e.column + 1, &e.expected ) )?;
+			items.push( ( item, priority ) );
+		}
+
+		if items.len() == 0
+		{
+			return Err( ParseError::new( 1, 1, "an item and its priority" ) );
+		}
+		return Alphabet::from_priorities( &items );
+	}
+
+	pub fn len( self: &Self ) -> usize
+	{
+		return self.items.len();
+	}
+
+	pub fn is_empty( self: &Self ) -> bool
+	{
+		return self.items.len() == 0;
+	}
+
+	pub fn item( self: &Self, index: usize ) -> char
+	{
+		return self.items[ index ].0;
+	}
+
+	pub fn priority( self: &Self, index: usize ) -> i32
+	{
+		return self.items[ index ].1;
+	}
+
+	pub fn index_of( self: &Self, item: char ) -> Option< usize >
+	{
+		return self.index.get( &item ).copied();
+	}
+}
+
+// Every rucksack, as indices into the alphabet
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Rucksacks
+{
+	pub alphabet: Alphabet,
+	pub sacks: Vec< Vec< usize > >,
+}
+
+pub fn parse( input: &str ) -> Result< Rucksacks, ParseError >
+{
+	return parse_with( Alphabet::standard(), input );
+}
+
+pub fn parse_with( alphabet: Alphabet, input: &str ) -> Result< Rucksacks, ParseError >
+{
+	let mut sacks: Vec< Vec< usize > > = Vec::new();
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		if cur_line.len() == 0
+		{
+			break;
+		}
+
+		let mut sack: Vec< usize > = Vec::new();
+		for ( col, c ) in cur_line.chars().enumerate()
+		{
+			match alphabet.index_of( c )
+			{
+				Some( item ) => sack.push( item ),
+				None => return Err( ParseError::new( i + 1, col + 1, &alphabet.expected ) ),
+			}
+		}
+		sacks.push( sack );
+	}
+	return Ok( Rucksacks { alphabet, sacks } );
+}
+
+// The items every one of `sets` has in common
+pub fn common_items( sets: &[ &[ usize ] ] ) -> BTreeSet< usize >
+{
+	let mut common: BTreeSet< usize > = match sets.first()
+	{
+		Some( first ) => first.iter().copied().collect(),
+		None => return BTreeSet::new(),
+	};
+	for set in &sets[ 1.. ]
+	{
+		let items: BTreeSet< usize > = set.iter().copied().collect();
+		common = common.intersection( &items ).copied().collect();
+	}
+	return common;
+}
+
+// A rucksack's two compartments. With an odd number of items the middle
+// one goes in the second.
+pub fn compartments( sack: &[ usize ] ) -> ( &[ usize ], &[ usize ] )
+{
+	return sack.split_at( sack.len() / 2 );
+}
+
+// Each rucksack split into groups of `group_size`, leaving out any at the
+// end that don't make a full group
+pub fn groups( rucksacks: &Rucksacks, group_size: usize ) -> impl Iterator< Item = &[ Vec< usize > ] >
+{
+	return rucksacks.sacks.chunks_exact( group_size );
+}
+
+// What an item shared by a set of rucksacks is worth. There should only
+// be one; if there are more the first in the alphabet counts.
+fn shared_priority( alphabet: &Alphabet, common: &BTreeSet< usize > ) -> i32
+{
+	return match common.first()
+	{
+		Some( item ) => alphabet.priority( *item ),
+		None => 0,
+	};
+}
+
+// Both answers at once
+#[derive(Eq,PartialEq,Copy,Clone,Debug)]
+pub struct Totals
+{
+	pub compartments: i32,
+	pub badges: i32,
+}
+
+pub fn solve( rucksacks: &Rucksacks, group_size: usize ) -> Option< Totals >
+{
+	if group_size == 0
+	{
+		return None;
+	}
+
+	let alphabet = &rucksacks.alphabet;
+
+	let mut compartment_total = 0;
+	for sack in &rucksacks.sacks
+	{
+		let ( first, second ) = compartments( sack );
+		compartment_total += shared_priority( alphabet, &common_items( &[ first, second ] ) );
+	}
+
+	let mut badge_total = 0;
+	for group in groups( rucksacks, group_size )
+	{
+		let members: Vec< &[ usize ] > = group.iter().map( | sack | sack.as_slice() ).collect();
+		badge_total += shared_priority( alphabet, &common_items( &members ) );
+	}
+
+	return Some( Totals { compartments: compartment_total, badges: badge_total } );
+}
+
+// Elves travel in threes
+pub const GROUP_SIZE: usize = 3;
+
+pub fn part1( rucksacks: &Rucksacks ) -> Option< i32 >
+{
+	return solve( rucksacks, GROUP_SIZE ).map( | totals | totals.compartments );
+}
+
+pub fn part2( rucksacks: &Rucksacks ) -> Option< i32 >
+{
+	return solve( rucksacks, GROUP_SIZE ).map( | totals | totals.badges );
+}
+
+// Something about a group that doesn't fit the puzzle. Rucksacks are
+// numbered from 1, the same as their lines in the input.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub enum Anomaly
+{
+	NoBadge,
+	SeveralBadges( Vec< char > ),
+	OddLength( usize ),
+	NothingMisplaced( usize ),
+	SeveralMisplaced( usize, Vec< char > ),
+	IncompleteGroup,
+}
+
+impl fmt::Display for Anomaly
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		match self
+		{
+			Anomaly::NoBadge => f.write_str( "no item common to the whole group" ),
+			Anomaly::SeveralBadges( items ) => f.write_fmt( format_args!( "several items common to the whole group: {}", list_items( items ) ) ),
+			Anomaly::OddLength( sack ) => f.write_fmt( format_args!( "rucksack {} has an odd number of items", sack ) ),
+			Anomaly::NothingMisplaced( sack ) => f.write_fmt( format_args!( "rucksack {} has no item in both compartments", sack ) ),
+			Anomaly::SeveralMisplaced( sack, items ) => f.write_fmt( format_args!( "rucksack {} has several items in both compartments: {}", sack, list_items( items ) ) ),
+			Anomaly::IncompleteGroup => f.write_str( "not enough rucksacks left to fill the group" ),
+		}
+	}
+}
+
+fn list_items( items: &[ char ] ) -> String
+{
+	let names: Vec< String > = items.iter().map( | c | c.to_string() ).collect();
+	return names.join( ", " );
+}
+
+// One group of elves as the validator sees it. `members` are rucksack
+// numbers, from 1.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct GroupReport
+{
+	pub members: Vec< usize >,
+	pub badge: Option< char >,
+	pub anomalies: Vec< Anomaly >,
+}
+
+// Check every group, and every rucksack in it, has exactly one shared
+// item, including any leftover rucksacks that don't make a full group.
+// None if the groups are empty, the same as solve().
+pub fn validate( rucksacks: &Rucksacks, group_size: usize ) -> Option< Vec< GroupReport > >
+{
+	if group_size == 0
+	{
+		return None;
+	}
+
+	let alphabet = &rucksacks.alphabet;
+	let letters = | items: &BTreeSet< usize > | -> Vec< char > { items.iter().map( | i | alphabet.item( *i ) ).collect() };
+
+	let mut reports: Vec< GroupReport > = Vec::new();
+	for ( g, group ) in rucksacks.sacks.chunks( group_size ).enumerate()
+	{
+		let first = g * group_size + 1;
+		let members: Vec< usize > = ( first..first + group.len() ).collect();
+		let mut anomalies: Vec< Anomaly > = Vec::new();
+
+		for ( sack, items ) in members.iter().zip( group )
+		{
+			if items.len() % 2 == 1
+			{
+				anomalies.push( Anomaly::OddLength( *sack ) );
+			}
+
+			let ( left, right ) = compartments( items );
+			let misplaced = common_items( &[ left, right ] );
+			match misplaced.len()
+			{
+				0 => anomalies.push( Anomaly::NothingMisplaced( *sack ) ),
+				1 => {},
+				_ => anomalies.push( Anomaly::SeveralMisplaced( *sack, letters( &misplaced ) ) ),
+			}
+		}
+
+		let badge: Option< char >;
+		if group.len() < group_size
+		{
+			anomalies.push( Anomaly::IncompleteGroup );
+			badge = None;
+		}
+		else
+		{
+			let slices: Vec< &[ usize ] > = group.iter().map( | sack | sack.as_slice() ).collect();
+			let common = common_items( &slices );
+			badge = match common.len()
+			{
+				0 =>
+				{
+					anomalies.push( Anomaly::NoBadge );
+					None
+				},
+				1 => common.first().map( | i | alphabet.item( *i ) ),
+				_ =>
+				{
+					anomalies.push( Anomaly::SeveralBadges( letters( &common ) ) );
+					None
+				},
+			};
+		}
+
+		reports.push( GroupReport { members, badge, anomalies } );
+	}
+	return Some( reports );
+}
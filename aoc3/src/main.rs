@@ -1,111 +1,113 @@
-use std::io::{ self, BufRead };
+use std::env;
+use std::process;
+use aoc3::Alphabet;
+use aoc3::Rucksacks;
 
+const USAGE: &str = "usage: aoc3 [--group N] [--alphabet path] [--validate]";
 
-fn main_old()
+struct Args
 {
-	let mut lines = io::stdin().lock().lines();
-	let mut total = 0;
-	while let Some( line ) = lines.next()
-	{
-		let cur_line = line.unwrap();
-		if cur_line.len() == 0
-		{
-			break;
-		}
+	group_size: usize,
+	alphabet: Option< String >,
+	validate: bool,
+}
 
-		let mut present : u64 = 0;
-		let half = cur_line.len() / 2;
-		for t in cur_line.char_indices()
+fn parse_args() -> Result< Args, String >
+{
+	let mut args = env::args().skip( 1 );
+
+	let mut group_size = aoc3::GROUP_SIZE;
+	let mut alphabet: Option< String > = None;
+	let mut validate = false;
+
+	while let Some( arg ) = args.next()
+	{
+		match arg.as_str()
 		{
-			let ( i, c ) = t;
-			let bit_index = if c >= 'a' && c <= 'z' 
-			{ 
-				( c as i32 ) - ( 'a' as i32 )
-			} 
-			else
+			"--group" =>
 			{
-				26 + ( c as i32 ) - ( 'A' as i32 )
-			};
-			let bit = 1 << bit_index;
-			let priority = bit_index + 1;
-			if i < half
-			{
-				// record what we found
-				present |= bit;
-			}
-			else
-			{
-				//check for dups
-				if ( present & bit ) != 0
+				let value = args.next().ok_or( "--group needs a value" )?;
+				group_size = match value.parse::<usize>()
 				{
-					println!( "Found dup: {} pri {}", c, priority );
-					total += priority;
-					break;
-				}
-			}	
+					Ok( n ) if n > 0 => n,
+					_ => return Err( format!( "invalid group size {}", value ) ),
+				};
+			},
+			"--alphabet" =>
+			{
+				alphabet = Some( args.next().ok_or( "--alphabet needs a path" )? );
+			},
+			"--validate" => validate = true,
+			_ => return Err( format!( "unexpected argument {}", arg ) ),
 		}
-
 	}
-	println!( "Total: {}", total );
+
+	return Ok( Args { group_size, alphabet, validate } );
 }
 
-fn main()
+// Print every group with its members and badge, and any problems with
+// it. Returns false if there were any.
+fn validate( rucksacks: &Rucksacks, group_size: usize ) -> bool
 {
-	let mut lines = io::stdin().lock().lines();
-	let mut total = 0;
-	let mut member_count = 0;
-	let mut badge_accumulator: u64 = 0;
-	while let Some( line ) = lines.next()
+	let mut valid = true;
+	// the group size has already been checked, so there's always a report
+	let reports = aoc3::validate( rucksacks, group_size ).unwrap_or_default();
+	for ( g, report ) in reports.iter().enumerate()
 	{
-		let cur_line = line.unwrap();
-		if cur_line.len() == 0
+		let badge = match report.badge
 		{
-			break;
-		}
+			Some( badge ) => badge.to_string(),
+			None => "none".to_string(),
+		};
+		println!( "group {}: badge {}", g + 1, badge );
 
-		let mut present : u64 = 0;
-		for t in cur_line.char_indices()
+		for member in &report.members
 		{
-			let ( i, c ) = t;
-			let bit_index = if c >= 'a' && c <= 'z' 
-			{ 
-				( c as i32 ) - ( 'a' as i32 )
-			} 
-			else
-			{
-				26 + ( c as i32 ) - ( 'A' as i32 )
-			};
-			let bit = 1 << bit_index;
-			present |= bit;
+			let items: String = rucksacks.sacks[ member - 1 ].iter().map( | i | rucksacks.alphabet.item( *i ) ).collect();
+			println!( "  {:>5}  {}", member, items );
 		}
 
-		if badge_accumulator == 0
+		for anomaly in &report.anomalies
 		{
-			badge_accumulator = present;
+			println!( "  problem: {}", anomaly );
+			valid = false;
 		}
-		else
+	}
+	return valid;
+}
+
+fn main()
+{
+	let args = match parse_args()
+	{
+		Ok( args ) => args,
+		Err( e ) =>
 		{
-			badge_accumulator = badge_accumulator & present;
+			eprintln!( "{}\n{}", e, USAGE );
+			process::exit( 2 );
 		}
-		member_count += 1;
-	
-		if member_count == 3 
+	};
+
+	let alphabet = match &args.alphabet
+	{
+		Some( path ) => aoc_common::parse_file( path, Alphabet::parse ),
+		None => Alphabet::standard(),
+	};
+
+	let rucksacks = aoc_common::parse_stdin( | input | aoc3::parse_with( alphabet, input ) );
+
+	if args.validate
+	{
+		if !validate( &rucksacks, args.group_size )
 		{
-			for bit_index in 0..52
-			{
-				if ( badge_accumulator & ( 1 << bit_index ) ) != 0
-				{
-					total += bit_index + 1;
-					println!( "badge is {}", bit_index );
-					break;
-				}
-			}
-			
-			badge_accumulator = 0;
-			member_count = 0;
+			process::exit( 1 );
 		}
+		return;
 	}
 
-	println!( "Total: {}", total );
+	if let Some( totals ) = aoc3::solve( &rucksacks, args.group_size )
+	{
+		println!( "Compartment total: {}", totals.compartments );
+		println!( "Badge total: {}", totals.badges );
+	}
 }
-
@@ -0,0 +1,168 @@
+use aoc_common::coverage;
+use aoc_common::Interval;
+use aoc_common::IntervalSet;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+// The elves assigned together on one line of the roster. The puzzle has
+// two per line, but any number works.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Group
+{
+	pub elves: Vec< Interval >,
+}
+
+// start-end, where the end can't come before the start
+fn parse_range( scanner: &mut Scanner ) -> Result< Interval, ParseError >
+{
+	let s = scanner.number::<i64>()?;
+	scanner.literal( "-" )?;
+	let end_column = scanner.column();
+	let e = scanner.number::<i64>()?;
+	if e < s
+	{
+		return Err( scanner.error_at( end_column, "a section at or after the start of the range" ) );
+	}
+	return Ok( Interval::new( s, e ) );
+}
+
+pub fn parse( input: &str ) -> Result< Vec< Group >, ParseError >
+{
+	let mut groups: Vec< Group > = Vec::new();
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		if cur_line.len() == 0
+		{
+			break;
+		}
+
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		let mut elves: Vec< Interval > = vec![ parse_range( &mut scanner )? ];
+		while !scanner.is_done()
+		{
+			scanner.literal( "," )?;
+			elves.push( parse_range( &mut scanner )? );
+		}
+
+		groups.push( Group { elves } );
+	}
+	return Ok( groups );
+}
+
+// How the elves in one group get in each other's way
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct GroupReport
+{
+	// elves, by position in the group, whose sections the others already
+	// cover between them. Two elves with the same sections are both
+	// redundant, though only one of them could be dropped.
+	pub redundant: Vec< usize >,
+
+	// sections more than one elf in the group cleans
+	pub shared: IntervalSet,
+}
+
+pub fn group_report( group: &Group ) -> GroupReport
+{
+	let mut redundant: Vec< usize > = Vec::new();
+	for ( i, elf ) in group.elves.iter().enumerate()
+	{
+		let others: IntervalSet = group.elves.iter().enumerate()
+			.filter( | ( j, _ ) | *j != i )
+			.map( | ( _, other ) | *other )
+			.collect();
+		if others.contains_interval( elf )
+		{
+			redundant.push( i );
+		}
+	}
+
+	let shared: IntervalSet = coverage( &group.elves ).iter()
+		.filter( | ( _, depth ) | *depth > 1 )
+		.map( | ( run, _ ) | *run )
+		.collect();
+
+	return GroupReport { redundant, shared };
+}
+
+// Everything we know about how the assignments line up, from one pass
+// over the groups
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Analysis
+{
+	// groups where one elf's sections are a subset of another's
+	pub contained: usize,
+
+	// groups where at least two elves share a section
+	pub overlapping: usize,
+
+	// sections cleaned by more than one elf in the same group, added up
+	// over every group
+	pub overlap_sections: i64,
+
+	// how many elves clean each section, as runs of the same depth
+	pub coverage: Vec< ( Interval, usize ) >,
+}
+
+impl Analysis
+{
+	// The sections cleaned by the most elves, and how many that is
+	pub fn busiest( self: &Self ) -> ( Vec< Interval >, usize )
+	{
+		let depth = self.coverage.iter().map( | ( _, d ) | *d ).max().unwrap_or( 0 );
+		let runs = self.coverage.iter().filter( | ( _, d ) | *d == depth ).map( | ( i, _ ) | *i ).collect();
+		return ( runs, depth );
+	}
+}
+
+// true if some elf's sections are a subset of another elf's
+fn has_contained( group: &Group ) -> bool
+{
+	for ( i, a ) in group.elves.iter().enumerate()
+	{
+		for ( j, b ) in group.elves.iter().enumerate()
+		{
+			if i != j && a.contains_interval( b )
+			{
+				return true;
+			}
+		}
+	}
+	return false;
+}
+
+pub fn analyze( groups: &[ Group ] ) -> Analysis
+{
+	let mut contained = 0;
+	let mut overlapping = 0;
+	let mut overlap_sections = 0;
+	let mut assignments: Vec< Interval > = Vec::new();
+
+	for group in groups
+	{
+		if has_contained( group )
+		{
+			contained += 1;
+		}
+
+		let shared = group_report( group ).shared;
+		if !shared.is_empty()
+		{
+			overlapping += 1;
+			overlap_sections += shared.len();
+		}
+		assignments.extend( &group.elves );
+	}
+
+	return Analysis { contained, overlapping, overlap_sections, coverage: coverage( &assignments ) };
+}
+
+pub fn part1( groups: &[ Group ] ) -> Option< usize >
+{
+	return Some( analyze( groups ).contained );
+}
+
+pub fn part2( groups: &[ Group ] ) -> Option< usize >
+{
+	return Some( analyze( groups ).overlapping );
+}
@@ -1,48 +1,65 @@
-use std::io::{ self, BufRead };
+use std::env;
+use std::process;
+use aoc_common::Interval;
 
-fn range_in_range( s1: i32, e1: i32, s2: i32, e2: i32 ) -> bool
-{
-	return s1 >= s2 && e1 <= e2;
-}
+const USAGE: &str = "usage: aoc4 [--coverage] [--groups]";
 
-fn strict_subrange( s1: i32, e1: i32, s2: i32, e2: i32 ) -> bool
+fn sections( interval: &Interval ) -> String
 {
-	return range_in_range( s1, e1, s2, e2 ) || range_in_range( s2, e2, s1, e1 );
-}
-
-fn overlap( s1: i32, e1: i32, s2: i32, e2: i32 ) -> bool
-{
-	return !( s1 > e2 || s2 > e1 );
+	if interval.start == interval.end
+	{
+		return interval.start.to_string();
+	}
+	return format!( "{}-{}", interval.start, interval.end );
 }
 
 fn main()
 {
-	let mut lines = io::stdin().lock().lines();
-	let mut count = 0;
-	while let Some( line ) = lines.next()
+	let mut show_coverage = false;
+	let mut show_groups = false;
+	for arg in env::args().skip( 1 )
 	{
-		let cur_line = line.unwrap();
-		if cur_line.len() == 0
+		match arg.as_str()
 		{
-			break;
+			"--coverage" => show_coverage = true,
+			"--groups" => show_groups = true,
+			_ =>
+			{
+				eprintln!( "unexpected argument {}\n{}", arg, USAGE );
+				process::exit( 2 );
+			}
 		}
+	}
+
+	let groups = aoc_common::parse_stdin( aoc4::parse );
+	let analysis = aoc4::analyze( &groups );
 
-		let v: Vec<&str> = cur_line.split( |c| c == ',' || c == '-' ).collect();
-		
-		let s1 = v[0].parse::<i32>().unwrap();
-		let e1 = v[1].parse::<i32>().unwrap();
-		let s2 = v[2].parse::<i32>().unwrap();
-		let e2 = v[3].parse::<i32>().unwrap();
+	println!( "Fully contained: {}", analysis.contained );
+	println!( "Overlapping: {}", analysis.overlapping );
+	println!( "Sections in overlaps: {}", analysis.overlap_sections );
 
-		assert!( s1 <= e1 );
-		assert!( s2 <= e2 );
+	let ( busiest, depth ) = analysis.busiest();
+	let runs: Vec< String > = busiest.iter().map( sections ).collect();
+	println!( "Busiest sections: {} ({} elves)", runs.join( ", " ), depth );
 
-		//if strict_subrange( s1, e1, s2, e2 )
-		if overlap( s1, e1, s2, e2 )
+	if show_coverage
+	{
+		println!( "sections     elves" );
+		for ( run, depth ) in &analysis.coverage
 		{
-			count += 1;
+			println!( "{:<11}  {:>5}", sections( run ), depth );
 		}
+	}
 
+	if show_groups
+	{
+		// elves are numbered from 1 along the line
+		for ( i, group ) in groups.iter().enumerate()
+		{
+			let report = aoc4::group_report( group );
+			let redundant: Vec< String > = report.redundant.iter().map( | elf | ( elf + 1 ).to_string() ).collect();
+			let shared: Vec< String > = report.shared.intervals().iter().map( sections ).collect();
+			println!( "line {}: redundant elves [{}], shared sections [{}]", i + 1, redundant.join( ", " ), shared.join( ", " ) );
+		}
 	}
-	println!( "Count: {}", count );
 }
@@ -0,0 +1,451 @@
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+#[derive(Eq,PartialEq,Copy,Clone,Debug)]
+pub struct Move
+{
+	pub n: usize,
+	pub from: usize,
+	pub to: usize,
+}
+
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Crates
+{
+	// the number under each stack in the drawing
+	pub labels: Vec< usize >,
+
+	// bottom to top
+	pub stacks: Vec< Vec< String > >,
+	pub moves: Vec< Move >,
+}
+
+// The runs of non-space characters in a line, with the columns they start
+// and end at, counting characters from 0
+fn tokens( line: &str ) -> Vec< ( usize, usize, String ) >
+{
+	let mut found: Vec< ( usize, usize, String ) > = Vec::new();
+	let mut current: Option< ( usize, String ) > = None;
+	for ( col, c ) in line.chars().enumerate()
+	{
+		if c.is_whitespace()
+		{
+			if let Some( ( start, text ) ) = current.take()
+			{
+				found.push( ( start, col - 1, text ) );
+			}
+		}
+		else
+		{
+			current.get_or_insert( ( col, String::new() ) ).1.push( c );
+		}
+	}
+	if let Some( ( start, text ) ) = current
+	{
+		found.push( ( start, start + text.chars().count() - 1, text ) );
+	}
+	return found;
+}
+
+fn describe_stacks( labels: &[ usize ] ) -> String
+{
+	if labels.iter().enumerate().all( | ( i, label ) | *label == i + 1 )
+	{
+		return format!( "a stack from 1 to {}", labels.len() );
+	}
+	let names: Vec< String > = labels.iter().map( | l | l.to_string() ).collect();
+	return format!( "one of the stacks {}", names.join( ", " ) );
+}
+
+// Stack number in the input, index into the stacks for us
+fn parse_stack( scanner: &mut Scanner, labels: &[ usize ] ) -> Result< usize, ParseError >
+{
+	let column = scanner.column();
+	let n = scanner.number::<usize>()?;
+	return match labels.iter().position( | l | *l == n )
+	{
+		Some( index ) => Ok( index ),
+		None => Err( scanner.error_at( column, &describe_stacks( labels ) ) ),
+	};
+}
+
+// The drawing is rows of crates like `[A]`, with the stack numbers on the
+// row under them. Crates belong to whichever number they sit over, so the
+// stacks can be any width, lines can be trimmed, and both crate names and
+// stack numbers can be more than one character long. Names can't have
+// spaces in them.
+pub fn parse( input: &str ) -> Result< Crates, ParseError >
+{
+	let lines: Vec< &str > = input.lines().collect();
+
+	let drawing_end = lines.iter().position( | l | l.len() == 0 ).unwrap_or( lines.len() );
+	if drawing_end == 0
+	{
+		return Err( ParseError::new( 1, 1, "a drawing of the stacks" ) );
+	}
+
+	// the numbers under the stacks
+	let label_line = drawing_end - 1;
+	let label_tokens = tokens( lines[ label_line ] );
+	if label_tokens.len() == 0
+	{
+		return Err( ParseError::new( label_line + 1, 1, "a row of stack numbers" ) );
+	}
+	let mut labels: Vec< usize > = Vec::new();
+	for ( start, _, text ) in &label_tokens
+	{
+		match text.parse::< usize >()
+		{
+			Ok( label ) if !labels.contains( &label ) => labels.push( label ),
+			Ok( _ ) => return Err( ParseError::new( label_line + 1, start + 1, "a stack number not already used" ) ),
+			Err( _ ) => return Err( ParseError::new( label_line + 1, start + 1, "a stack number" ) ),
+		}
+	}
+
+	// the crates, top row first
+	let mut stack: Vec< Vec< String > > = vec![ Vec::new(); labels.len() ];
+	for i in 0..label_line
+	{
+		for ( start, end, text ) in tokens( lines[ i ] )
+		{
+			if !text.starts_with( '[' )
+			{
+				return Err( ParseError::new( i + 1, start + 1, "`[`" ) );
+			}
+			if text.len() < 2 || !text.ends_with( ']' )
+			{
+				return Err( ParseError::new( i + 1, end + 2, "`]`" ) );
+			}
+			if text.len() == 2
+			{
+				return Err( ParseError::new( i + 1, start + 2, "a crate name" ) );
+			}
+
+			let under: Vec< usize > = ( 0..labels.len() )
+				.filter( | l | label_tokens[ *l ].0 <= end && start <= label_tokens[ *l ].1 )
+				.collect();
+			if under.len() != 1
+			{
+				return Err( ParseError::new( i + 1, start + 1, "a crate over exactly one stack number" ) );
+			}
+
+			stack[ under[ 0 ] ].push( text[ 1..text.len() - 1 ].to_string() );
+		}
+	}
+
+	// stacks come in top to bottom, so we want to reverse them
+	for i in 0..stack.len()
+	{
+		stack[ i ].reverse();
+	}
+
+	// now read the instructions, keeping track of how tall each stack is
+	// so we can catch moves of crates that aren't there
+	let mut heights: Vec< usize > = stack.iter().map( | s | s.len() ).collect();
+	let mut moves: Vec< Move > = Vec::new();
+	for i in drawing_end + 1..lines.len()
+	{
+		let cur_line = lines[ i ];
+		if cur_line.len() == 0
+		{
+			break;
+		}
+
+		// move N from A to B
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		scanner.literal( "move " )?;
+		let n_column = scanner.column();
+		let n = scanner.number::<usize>()?;
+		scanner.literal( " from " )?;
+		let from = parse_stack( &mut scanner, &labels )?;
+		scanner.literal( " to " )?;
+		let to = parse_stack( &mut scanner, &labels )?;
+		scanner.end()?;
+
+		if n > heights[ from ]
+		{
+			let expected = format!( "at most {} crates, the height of stack {}", heights[ from ], labels[ from ] );
+			return Err( scanner.error_at( n_column, &expected ) );
+		}
+		heights[ from ] -= n;
+		heights[ to ] += n;
+
+		moves.push( Move { n, from, to } );
+	}
+
+	return Ok( Crates { labels, stacks: stack, moves } );
+}
+
+// The crate on top of each stack, with a space for any that are empty
+pub fn top_crates( stack: &[ Vec< String > ] ) -> String
+{
+	let mut res: String = "".to_owned();
+	for i in 0..stack.len()
+	{
+		match stack[ i ].last()
+		{
+			Some( top ) => res.push_str( top ),
+			None => res.push( ' ' ),
+		}
+	}
+
+	return res;
+}
+
+// A model of crane, which decides what order crates end up in when it
+// moves several at once
+pub trait Crane
+{
+	fn name( self: &Self ) -> String;
+
+	// Carry out one move. The parser has already checked the stacks exist,
+	// and that there are enough crates to move as long as every move so far
+	// took exactly n crates from one stack to the other. A crane is free to
+	// do something else, and a Journal can still undo it, but then a later
+	// move can find too few crates and the crane has to cope with that
+	// itself. The cranes here panic.
+	fn apply( self: &Self, stacks: &mut [ Vec< String > ], m: &Move );
+}
+
+// Lift the top `n` crates off `from` in one go and set them down on `to`
+// in the same order
+fn lift( stacks: &mut [ Vec< String > ], from: usize, to: usize, n: usize )
+{
+	assert!( n <= stacks[ from ].len() );
+	let start = stacks[ from ].len() - n;
+	let lifted: Vec< String > = stacks[ from ].drain( start.. ).collect();
+	stacks[ to ].extend( lifted );
+}
+
+// The CrateMover 9000 moves one crate at a time
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000
+{
+	fn name( self: &Self ) -> String
+	{
+		return "CrateMover 9000".to_string();
+	}
+
+	fn apply( self: &Self, stacks: &mut [ Vec< String > ], m: &Move )
+	{
+		for _ in 0..m.n
+		{
+			lift( stacks, m.from, m.to, 1 );
+		}
+	}
+}
+
+// The CrateMover 9001 picks up all n crates at once, keeping their order
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001
+{
+	fn name( self: &Self ) -> String
+	{
+		return "CrateMover 9001".to_string();
+	}
+
+	fn apply( self: &Self, stacks: &mut [ Vec< String > ], m: &Move )
+	{
+		lift( stacks, m.from, m.to, m.n );
+	}
+}
+
+// Picks up as many as `max_lift` crates at a time, keeping their order
+// within each lift. A limit of 1 is the 9000; one at least as big as any
+// move is the 9001.
+pub struct LimitedCrane
+{
+	max_lift: usize,
+}
+
+impl LimitedCrane
+{
+	// A crane that can't lift anything would never finish a move, so a
+	// limit of 0 is taken as 1
+	pub fn new( max_lift: usize ) -> LimitedCrane
+	{
+		return LimitedCrane { max_lift: max_lift.max( 1 ) };
+	}
+
+	pub fn max_lift( self: &Self ) -> usize
+	{
+		return self.max_lift;
+	}
+}
+
+impl Crane for LimitedCrane
+{
+	fn name( self: &Self ) -> String
+	{
+		return format!( "crane lifting {} at a time", self.max_lift );
+	}
+
+	fn apply( self: &Self, stacks: &mut [ Vec< String > ], m: &Move )
+	{
+		let mut left = m.n;
+		while left > 0
+		{
+			let n = left.min( self.max_lift );
+			lift( stacks, m.from, m.to, n );
+			left -= n;
+		}
+	}
+}
+
+// The stacks after `crane` has carried out every move
+pub fn run( crates: &Crates, crane: &dyn Crane ) -> Vec< Vec< String > >
+{
+	let mut stacks = crates.stacks.clone();
+	for m in &crates.moves
+	{
+		crane.apply( &mut stacks, m );
+	}
+	return stacks;
+}
+
+pub fn part1( crates: &Crates ) -> Option< String >
+{
+	return Some( top_crates( &run( crates, &CrateMover9000 ) ) );
+}
+
+pub fn part2( crates: &Crates ) -> Option< String >
+{
+	return Some( top_crates( &run( crates, &CrateMover9001 ) ) );
+}
+
+// A move as it was written in the input
+pub fn describe_move( crates: &Crates, m: &Move ) -> String
+{
+	return format!( "move {} from {} to {}", m.n, crates.labels[ m.from ], crates.labels[ m.to ] );
+}
+
+// `text` in the middle of `width` columns
+fn centered( text: &str, width: usize ) -> String
+{
+	let len = text.chars().count();
+	let left = ( width - len ) / 2;
+	return format!( "{}{}{}", " ".repeat( left ), text, " ".repeat( width - len - left ) );
+}
+
+// Draw the stacks the way the puzzle input does, with the stack numbers
+// along the bottom, so parse() can read it back. Columns widen to fit
+// long crate names and stack numbers.
+pub fn render( labels: &[ usize ], stacks: &[ Vec< String > ] ) -> String
+{
+	let label_names: Vec< String > = labels.iter().map( | l | l.to_string() ).collect();
+	let widths: Vec< usize > = ( 0..stacks.len() )
+		.map( | i | stacks[ i ].iter().map( | c | c.chars().count() + 2 ).chain( [ 3, label_names[ i ].len() ] ).max().unwrap() )
+		.collect();
+	let height = stacks.iter().map( | s | s.len() ).max().unwrap_or( 0 );
+
+	let mut out = String::new();
+	for row in ( 0..height ).rev()
+	{
+		let cells: Vec< String > = ( 0..stacks.len() )
+			.map( | i | match stacks[ i ].get( row )
+			{
+				Some( name ) => centered( &format!( "[{}]", name ), widths[ i ] ),
+				None => " ".repeat( widths[ i ] ),
+			} )
+			.collect();
+		out.push_str( &cells.join( " " ) );
+		out.push( '\n' );
+	}
+
+	let cells: Vec< String > = ( 0..stacks.len() ).map( | i | centered( &label_names[ i ], widths[ i ] ) ).collect();
+	out.push_str( &cells.join( " " ) );
+	out.push( '\n' );
+	return out;
+}
+
+// Steps a crane through the moves one at a time, remembering enough to
+// take each move back again. `position` is how many moves have been made.
+pub struct Journal< 'a >
+{
+	crates: &'a Crates,
+	crane: &'a dyn Crane,
+	stacks: Vec< Vec< String > >,
+
+	// for each move made so far, every stack it changed as it was before,
+	// whichever stacks the crane chose to touch
+	undo: Vec< Vec< ( usize, Vec< String > ) > >,
+}
+
+impl< 'a > Journal< 'a >
+{
+	pub fn new( crates: &'a Crates, crane: &'a dyn Crane ) -> Journal< 'a >
+	{
+		return Journal { crates, crane, stacks: crates.stacks.clone(), undo: Vec::new() };
+	}
+
+	pub fn stacks( self: &Self ) -> &[ Vec< String > ]
+	{
+		return &self.stacks;
+	}
+
+	pub fn position( self: &Self ) -> usize
+	{
+		return self.undo.len();
+	}
+
+	pub fn len( self: &Self ) -> usize
+	{
+		return self.crates.moves.len();
+	}
+
+	pub fn is_empty( self: &Self ) -> bool
+	{
+		return self.crates.moves.len() == 0;
+	}
+
+	// Make the next move, if there is one
+	pub fn redo( self: &mut Self ) -> bool
+	{
+		let m = match self.crates.moves.get( self.position() )
+		{
+			Some( m ) => m,
+			None => return false,
+		};
+
+		let before = self.stacks.clone();
+		self.crane.apply( &mut self.stacks, m );
+
+		let changed: Vec< ( usize, Vec< String > ) > = before.into_iter()
+			.enumerate()
+			.filter( | ( i, stack ) | *stack != self.stacks[ *i ] )
+			.collect();
+		self.undo.push( changed );
+		return true;
+	}
+
+	// Take back the last move made, if there was one
+	pub fn undo( self: &mut Self ) -> bool
+	{
+		let changed = match self.undo.pop()
+		{
+			Some( changed ) => changed,
+			None => return false,
+		};
+
+		for ( i, stack ) in changed
+		{
+			self.stacks[ i ] = stack;
+		}
+		return true;
+	}
+
+	// Undo or redo until `position` moves have been made, stopping at the
+	// last move if there aren't that many
+	pub fn jump_to( self: &mut Self, position: usize )
+	{
+		while self.position() > position && self.undo() {}
+		while self.position() < position && self.redo() {}
+	}
+
+	pub fn render( self: &Self ) -> String
+	{
+		return render( &self.crates.labels, &self.stacks );
+	}
+}
@@ -1,110 +1,126 @@
+use std::env;
+use std::process;
+use aoc5::Crane;
 
-use std::io::{ self, BufRead };
+const USAGE: &str = "usage: aoc5 [--max-lift K | --crane 9000|9001] [--frames | --at N]";
 
-
-fn main()
+struct Args
 {
-	let mut lines = io::stdin().lock().lines();
+	max_lift: Option< usize >,
+	crane: Box< dyn Crane >,
+	frames: bool,
+	at: Option< usize >,
+}
 
-	let mut stack: Vec< Vec< char > > = Vec::new();
+fn parse_args() -> Result< Args, String >
+{
+	let mut args = env::args().skip( 1 );
 
-	// read the initial state
-	while let Some( line ) = lines.next()
+	let mut max_lift: Option< usize > = None;
+	let mut crane: Option< Box< dyn Crane > > = None;
+	let mut frames = false;
+	let mut at: Option< usize > = None;
+	while let Some( arg ) = args.next()
 	{
-		let cur_line = line.unwrap();
-		if cur_line.len() == 0
-		{
-			// done with initial state
-			break;
-		}
-
-		if stack.len() == 0
+		match arg.as_str()
 		{
-			// build the empty stack vectors
-			let stack_count = ( cur_line.len() + 1 ) / 4;
-			for _i in 0..stack_count
+			"--max-lift" =>
 			{
-				stack.push( Vec::new() );
-			}
-		}
-			
-		// skip the line with the numbers
-		if cur_line.chars().nth( 1 ) == Some( '1' ) 
-		{
-			continue;
-		}
-
-		assert!( stack.len() * 4 == cur_line.len() + 1 );
-
-		for i in 0..stack.len()
-		{
-			let c = cur_line.chars().nth( i * 4 + 1 );
-			if c != Some( ' ' )
+				let value = args.next().ok_or( "--max-lift needs a value" )?;
+				max_lift = match value.parse::<usize>()
+				{
+					Ok( k ) if k > 0 => Some( k ),
+					_ => return Err( format!( "invalid lift size {}", value ) ),
+				};
+			},
+			"--crane" =>
+			{
+				let value = args.next().ok_or( "--crane needs a value" )?;
+				crane = match value.as_str()
+				{
+					"9000" => Some( Box::new( aoc5::CrateMover9000 ) ),
+					"9001" => Some( Box::new( aoc5::CrateMover9001 ) ),
+					_ => return Err( format!( "unknown crane {}", value ) ),
+				};
+			},
+			"--frames" => frames = true,
+			"--at" =>
 			{
-				stack[ i ].push( c.unwrap() );
-			}
+				let value = args.next().ok_or( "--at needs a value" )?;
+				at = Some( value.parse::<usize>().map_err( |_| format!( "invalid move number {}", value ) )? );
+			},
+			_ => return Err( format!( "unexpected argument {}", arg ) ),
 		}
 	}
 
-	// stacks come in top to bottom, so we want to reverse them
-	for i in 0..stack.len()
+	if frames && at.is_some()
 	{
-		stack[ i ].reverse();
+		return Err( "--frames and --at can't be used together".to_string() );
 	}
 
-	println!( "Before any moves:");
-	for s in &stack
+	// a limited crane is the one to watch if there is one, so it can't be
+	// asked for alongside another
+	let crane: Box< dyn Crane > = match ( max_lift, crane )
 	{
-		println!( "{:?}", s );
-	}
+		( Some( _ ), Some( _ ) ) => return Err( "--max-lift and --crane can't be used together".to_string() ),
+		( Some( max_lift ), None ) => Box::new( aoc5::LimitedCrane::new( max_lift ) ),
+		( None, Some( crane ) ) => crane,
+		( None, None ) => Box::new( aoc5::CrateMover9000 ),
+	};
+
+	return Ok( Args { max_lift, crane, frames, at } );
+}
 
-	// now read the instructions
-	while let Some( line ) = lines.next()
+fn main()
+{
+	let args = match parse_args()
 	{
-		let cur_line = line.unwrap();
-		if cur_line.len() == 0
+		Ok( args ) => args,
+		Err( e ) =>
 		{
-			// done with initial state
-			break;
+			eprintln!( "{}\n{}", e, USAGE );
+			process::exit( 2 );
 		}
+	};
 
-		// move N from A to B
-		let args : Vec<&str> = cur_line.split( ' ' ).collect();
-		let n = args[1].parse::<i32>().unwrap();
-		let a = args[3].parse::<usize>().unwrap() - 1;
-		let b = args[5].parse::<usize>().unwrap() - 1;
+	let crates = aoc_common::parse_stdin( aoc5::parse );
 
-		assert!( n <= stack[ a ].len() as i32 );
-		let start = stack[ a ].len() as i32 - n ;
-		for i in 0..n
-		{
-			let c = stack[a][( start + i ) as usize ];
-			stack[b].push( c );
-		}
-		for _ in 0..n
+	// every frame as it would be drawn in the input, each after the move
+	// that made it, so they can be diffed or stitched together
+	if args.frames
+	{
+		let mut journal = aoc5::Journal::new( &crates, args.crane.as_ref() );
+		print!( "{}", journal.render() );
+		for m in &crates.moves
 		{
-			stack[a].pop();
+			journal.redo();
+			println!( "\n{}", aoc5::describe_move( &crates, m ) );
+			print!( "{}", journal.render() );
 		}
+		return;
+	}
 
-		println!( "After move {} from {} to {}", n, a, b );
-		for s in &stack
+	if let Some( at ) = args.at
+	{
+		if at > crates.moves.len()
 		{
-			println!( "{:?}", s );
+			eprintln!( "there are only {} moves", crates.moves.len() );
+			process::exit( 1 );
 		}
-
+		let mut journal = aoc5::Journal::new( &crates, args.crane.as_ref() );
+		journal.jump_to( at );
+		print!( "{}", journal.render() );
+		return;
 	}
 
-	for s in &stack
+	let mut cranes: Vec< Box< dyn Crane > > = vec![ Box::new( aoc5::CrateMover9000 ), Box::new( aoc5::CrateMover9001 ) ];
+	if let Some( max_lift ) = args.max_lift
 	{
-		println!( "{:?}", s );
+		cranes.push( Box::new( aoc5::LimitedCrane::new( max_lift ) ) );
 	}
 
-	let mut res: String = "".to_owned();
-	for i in 0..stack.len()
+	for crane in &cranes
 	{
-		res.push_str( &stack[ i ].last().unwrap().to_string() );	
+		println!( "{}: {}", crane.name(), aoc5::top_crates( &aoc5::run( &crates, crane.as_ref() ) ) );
 	}
-
-
-	println!( "Result: {}", res );
 }
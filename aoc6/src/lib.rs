@@ -0,0 +1,330 @@
+use aoc_common::ParseError;
+use std::fmt;
+use std::io;
+use std::io::Read;
+
+// First marker position for each datastream, in input order
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Markers( pub Vec< Option< usize > > );
+
+impl fmt::Display for Markers
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		let mut out = String::new();
+		for marker in &self.0
+		{
+			if out.len() != 0
+			{
+				out.push_str( ", " );
+			}
+
+			match marker
+			{
+				Some( i ) => out.push_str( &i.to_string() ),
+				None => out.push_str( "not found" ),
+			}
+		}
+		f.write_str( &out )
+	}
+}
+
+// start-of-packet markers are 4 distinct characters, start-of-message 14
+pub const PACKET_MARKER_LEN: usize = 4;
+pub const MESSAGE_MARKER_LEN: usize = 14;
+
+// One window sliding over the stream: how many of each byte it holds and
+// how many different bytes that comes to
+struct Window
+{
+	len: usize,
+	counts: [ u32; 256 ],
+	distinct: usize,
+}
+
+// Finds the first marker of each size in a stream fed in a byte at a
+// time, keeping only the last few bytes, so a stream can be any length.
+// Markers are the number of bytes read by the end of the first window
+// that has no byte twice. A recording detector also keeps every later
+// window that has no byte twice, not just the first.
+pub struct Detector
+{
+	windows: Vec< Window >,
+	found: Vec< Option< usize > >,
+	all: Option< Vec< Vec< usize > > >,
+
+	// the last bytes read, as many as the longest window, in a ring
+	recent: Vec< u8 >,
+	position: usize,
+}
+
+impl Detector
+{
+	pub fn new( sizes: &[ usize ] ) -> Detector
+	{
+		let windows: Vec< Window > = sizes.iter().map( | len | Window { len: *len, counts: [ 0; 256 ], distinct: 0 } ).collect();
+		let longest = sizes.iter().copied().max().unwrap_or( 0 ).max( 1 );
+		return Detector { windows, found: vec![ None; sizes.len() ], all: None, recent: vec![ 0; longest ], position: 0 };
+	}
+
+	pub fn recording( sizes: &[ usize ] ) -> Detector
+	{
+		let mut detector = Detector::new( sizes );
+		detector.all = Some( vec![ Vec::new(); sizes.len() ] );
+		return detector;
+	}
+
+	pub fn push( self: &mut Self, byte: u8 )
+	{
+		self.position += 1;
+		let ring = self.recent.len();
+
+		for ( i, ( window, found ) ) in self.windows.iter_mut().zip( &mut self.found ).enumerate()
+		{
+			// an empty window never has a marker to find
+			if window.len == 0
+			{
+				continue;
+			}
+
+			// the byte falling out of the window has to be read before the
+			// new one can take its place in the ring
+			if self.position > window.len
+			{
+				let leaving = self.recent[ ( self.position - window.len - 1 ) % ring ] as usize;
+				window.counts[ leaving ] -= 1;
+				if window.counts[ leaving ] == 0
+				{
+					window.distinct -= 1;
+				}
+			}
+
+			window.counts[ byte as usize ] += 1;
+			if window.counts[ byte as usize ] == 1
+			{
+				window.distinct += 1;
+			}
+
+			if window.distinct == window.len
+			{
+				if found.is_none()
+				{
+					*found = Some( self.position );
+				}
+				if let Some( all ) = &mut self.all
+				{
+					all[ i ].push( self.position );
+				}
+			}
+		}
+
+		self.recent[ ( self.position - 1 ) % ring ] = byte;
+	}
+
+	// Every window has found its marker, so there's no need to read on,
+	// unless every marker is wanted
+	pub fn is_done( self: &Self ) -> bool
+	{
+		return self.all.is_none() && self.found.iter().all( | found | found.is_some() );
+	}
+
+	// The first marker for each size, in the order they were asked for
+	pub fn found( self: &Self ) -> &[ Option< usize > ]
+	{
+		return &self.found;
+	}
+
+	// Every marker for each size, if the detector was recording them
+	pub fn into_occurrences( self: Self ) -> Vec< Vec< usize > >
+	{
+		return self.all.unwrap_or_default();
+	}
+}
+
+pub fn first_markers( stream: &[ u8 ], sizes: &[ usize ] ) -> Vec< Option< usize > >
+{
+	let mut detector = Detector::new( sizes );
+	for byte in stream
+	{
+		if detector.is_done()
+		{
+			break;
+		}
+		detector.push( *byte );
+	}
+	return detector.found().to_vec();
+}
+
+// Feed each line of `reader` to its own detector, without holding more
+// than a buffer of it at a time, and hand each detector on with the
+// line's length once the line is over. Like parse(), a blank line ends
+// the input.
+fn scan_lines< R, F >( mut reader: R, sizes: &[ usize ], record: bool, mut line_done: F ) -> io::Result< () >
+	where R: Read, F: FnMut( Detector, usize )
+{
+	let detector = | | if record { Detector::recording( sizes ) } else { Detector::new( sizes ) };
+	let mut current = detector();
+	let mut line_len = 0;
+
+	let mut buffer = [ 0; 64 * 1024 ];
+	loop
+	{
+		let count = match reader.read( &mut buffer )
+		{
+			Ok( 0 ) => break,
+			Ok( count ) => count,
+			Err( e ) if e.kind() == io::ErrorKind::Interrupted => continue,
+			Err( e ) => return Err( e ),
+		};
+
+		for byte in &buffer[ ..count ]
+		{
+			if *byte == b'\n'
+			{
+				if line_len == 0
+				{
+					return Ok( () );
+				}
+				line_done( std::mem::replace( &mut current, detector() ), line_len );
+				line_len = 0;
+			}
+			else
+			{
+				line_len += 1;
+				if !current.is_done()
+				{
+					current.push( *byte );
+				}
+			}
+		}
+	}
+
+	// the last line doesn't always end in a newline
+	if line_len > 0
+	{
+		line_done( current, line_len );
+	}
+	return Ok( () );
+}
+
+// Look for the first marker of every size in each line of `reader` in
+// one pass. Returns the markers for each size, in the order of `sizes`.
+pub fn scan< R: Read >( reader: R, sizes: &[ usize ] ) -> io::Result< Vec< Markers > >
+{
+	let mut markers: Vec< Markers > = sizes.iter().map( |_| Markers( Vec::new() ) ).collect();
+	scan_lines( reader, sizes, false, | detector, _ |
+	{
+		for ( size, found ) in markers.iter_mut().zip( detector.found() )
+		{
+			size.0.push( *found );
+		}
+	} )?;
+	return Ok( markers );
+}
+
+// Every marker found in one line of input, for each size asked for
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct LineMarkers
+{
+	pub line: usize,
+	pub length: usize,
+	pub sizes: Vec< usize >,
+
+	// the end of every window with no byte twice, for each size
+	pub occurrences: Vec< Vec< usize > >,
+}
+
+impl LineMarkers
+{
+	// How far each marker is from the one before it, for one window size,
+	// or None if that size wasn't looked for
+	pub fn gaps( self: &Self, size: usize ) -> Option< Vec< usize > >
+	{
+		let index = self.sizes.iter().position( | s | *s == size )?;
+		return Some( self.occurrences[ index ].windows( 2 ).map( | pair | pair[ 1 ] - pair[ 0 ] ).collect() );
+	}
+}
+
+// Every marker of every size in each line of `reader`, not just the first
+pub fn scan_all< R: Read >( reader: R, sizes: &[ usize ] ) -> io::Result< Vec< LineMarkers > >
+{
+	let mut lines: Vec< LineMarkers > = Vec::new();
+	scan_lines( reader, sizes, true, | detector, length |
+	{
+		let line = lines.len() + 1;
+		lines.push( LineMarkers { line, length, sizes: sizes.to_vec(), occurrences: detector.into_occurrences() } );
+	} )?;
+	return Ok( lines );
+}
+
+fn join< T: ToString >( values: &[ T ], separator: &str ) -> String
+{
+	let values: Vec< String > = values.iter().map( | value | value.to_string() ).collect();
+	return values.join( separator );
+}
+
+// One row per line and size, with how many markers there were and where
+pub fn to_table( lines: &[ LineMarkers ] ) -> String
+{
+	let mut out = "line  size  count  markers\n".to_string();
+	for line in lines
+	{
+		for ( size, occurrences ) in line.sizes.iter().zip( &line.occurrences )
+		{
+			out.push_str( &format!( "{:<5} {:<5} {:<6} {}\n", line.line, size, occurrences.len(), join( occurrences, ", " ) ) );
+		}
+	}
+	return out;
+}
+
+// The same as an array of objects, one per line, with the markers keyed
+// by size:
+//
+//   [{"line":1,"length":30,"markers":{"4":[7,8],"14":[19]}}]
+pub fn to_json( lines: &[ LineMarkers ] ) -> String
+{
+	let objects: Vec< String > = lines.iter()
+		.map( | line |
+		{
+			let sizes: Vec< String > = line.sizes.iter().zip( &line.occurrences )
+				.map( | ( size, occurrences ) | format!( "\"{}\":[{}]", size, join( occurrences, "," ) ) )
+				.collect();
+			format!( "{{\"line\":{},\"length\":{},\"markers\":{{{}}}}}", line.line, line.length, sizes.join( "," ) )
+		} )
+		.collect();
+	return format!( "[{}]\n", objects.join( "," ) );
+}
+
+pub fn parse( input: &str ) -> Result< Vec< String >, ParseError >
+{
+	let mut streams: Vec< String > = Vec::new();
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		if cur_line.len() == 0
+		{
+			break;
+		}
+
+		if let Some( col ) = cur_line.find( | c: char | !c.is_ascii_lowercase() )
+		{
+			return Err( ParseError::new( i + 1, col + 1, "a character from a to z" ) );
+		}
+		streams.push( cur_line.to_string() );
+	}
+	return Ok( streams );
+}
+
+fn find_markers( streams: &[ String ], marker_len: usize ) -> Markers
+{
+	return Markers( streams.iter().map( | stream | first_markers( stream.as_bytes(), &[ marker_len ] )[ 0 ] ).collect() );
+}
+
+pub fn part1( streams: &[ String ] ) -> Option< Markers >
+{
+	return Some( find_markers( streams, PACKET_MARKER_LEN ) );
+}
+
+pub fn part2( streams: &[ String ] ) -> Option< Markers >
+{
+	return Some( find_markers( streams, MESSAGE_MARKER_LEN ) );
+}
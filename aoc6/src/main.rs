@@ -1,51 +1,107 @@
-use std::io::{ self, BufRead };
-use std::cmp;
+use std::env;
+use std::io;
+use std::process;
 
+const USAGE: &str = "usage: aoc6 [--size K]... [--all | --json]";
 
-fn process_line( input: String )
+enum Report
 {
-	println!( "Processing {:10}", &input[0..cmp::min( input.len(), 15 ) ] );
+	First,
+	Table,
+	Json,
+}
+
+struct Args
+{
+	// None for the puzzle's two sizes
+	sizes: Option< Vec< usize > >,
+	report: Report,
+}
 
-	let marker_len = 14;
-	for i in marker_len..input.len()
+fn parse_args() -> Result< Args, String >
+{
+	let mut args = env::args().skip( 1 );
+
+	let mut sizes: Vec< usize > = Vec::new();
+	let mut report = Report::First;
+	while let Some( arg ) = args.next()
 	{
-		let mut dup = false;
-		let start = i - marker_len;
-		let end = i;
-		for j in start..end
+		match arg.as_str()
 		{
-			for k in ( j + 1 )..end
+			"--size" =>
 			{
-				let a = input.chars().nth( j );
-				let b = input.chars().nth( k );
-				dup = dup || a == b;
-			}
-		}
-
-		if !dup 
-		{
-			println!( "  marker at {}", i );
-			return;
+				let value = args.next().ok_or( "--size needs a value" )?;
+				match value.parse::<usize>()
+				{
+					Ok( k ) if sizes.contains( &k ) => return Err( format!( "window size {} given twice", k ) ),
+					Ok( k ) if k > 0 => sizes.push( k ),
+					_ => return Err( format!( "invalid window size {}", value ) ),
+				}
+			},
+			"--all" | "--json" =>
+			{
+				if !matches!( report, Report::First )
+				{
+					return Err( "--all and --json can't be used together".to_string() );
+				}
+				report = if arg == "--all" { Report::Table } else { Report::Json };
+			},
+			_ => return Err( format!( "unexpected argument {}", arg ) ),
 		}
 	}
 
-	println!( "Marker not found" );
+	let sizes = if sizes.len() == 0 { None } else { Some( sizes ) };
+	return Ok( Args { sizes, report } );
+}
+
+fn read_failed( e: io::Error ) -> !
+{
+	eprintln!( "failed to read input: {}", e );
+	process::exit( 1 );
 }
 
 fn main()
 {
-	let mut lines = io::stdin().lock().lines();
+	let args = match parse_args()
+	{
+		Ok( args ) => args,
+		Err( e ) =>
+		{
+			eprintln!( "{}\n{}", e, USAGE );
+			process::exit( 2 );
+		}
+	};
+
+	// streams can be far too long to read in, so they're scanned as they
+	// arrive and every size is looked for in the same pass
+	let scan_sizes = args.sizes.clone().unwrap_or( vec![ aoc6::PACKET_MARKER_LEN, aoc6::MESSAGE_MARKER_LEN ] );
 
-	// read the initial state
-	while let Some( line ) = lines.next()
+	if !matches!( args.report, Report::First )
 	{
-		let cur_line = line.unwrap();
-		if cur_line.len() == 0
+		let lines = aoc6::scan_all( io::stdin().lock(), &scan_sizes ).unwrap_or_else( | e | read_failed( e ) );
+		match args.report
 		{
-			// done with initial state
-			break;
+			Report::Json => print!( "{}", aoc6::to_json( &lines ) ),
+			_ => print!( "{}", aoc6::to_table( &lines ) ),
 		}
+		return;
+	}
 
-		process_line( cur_line );
+	let markers = aoc6::scan( io::stdin().lock(), &scan_sizes ).unwrap_or_else( | e | read_failed( e ) );
+
+	match args.sizes
+	{
+		Some( sizes ) =>
+		{
+			for ( size, markers ) in sizes.iter().zip( &markers )
+			{
+				println!( "{} distinct: {}", size, markers );
+			}
+		},
+		None =>
+		{
+			println!( "Packet markers: {}", markers[ 0 ] );
+			println!( "Message markers: {}", markers[ 1 ] );
+		},
 	}
 }
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+
+// A directory holds the indices of what's in it
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub enum Kind
+{
+	File,
+	Dir( Vec< usize > ),
+}
+
+// A file or directory. A directory's size is everything under it, worked
+// out once the whole listing has been read.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Entry
+{
+	pub name: String,
+	pub parent: Option< usize >,
+	pub kind: Kind,
+	pub size: usize,
+}
+
+impl Entry
+{
+	pub fn is_dir( self: &Self ) -> bool
+	{
+		return matches!( self.kind, Kind::Dir( _ ) );
+	}
+}
+
+// Every entry in one list, pointing at each other by index, with the root
+// directory first. Entries always come after the directory they're in.
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct FileSystem
+{
+	pub entries: Vec< Entry >,
+
+	// each directory's entries by name, so big directories stay quick to
+	// look things up in
+	names: HashMap< usize, HashMap< String, usize > >,
+}
+
+pub const ROOT: usize = 0;
+
+impl FileSystem
+{
+	fn new() -> FileSystem
+	{
+		let root = Entry { name: "/".to_string(), parent: None, kind: Kind::Dir( Vec::new() ), size: 0 };
+		return FileSystem { entries: vec![ root ], names: HashMap::new() };
+	}
+
+	// The entry called `name` in directory `dir`, if it's been listed
+	pub fn child( self: &Self, dir: usize, name: &str ) -> Option< usize >
+	{
+		return self.names.get( &dir )?.get( name ).copied();
+	}
+
+	fn add( self: &mut Self, dir: usize, name: &str, kind: Kind, size: usize )
+	{
+		// listing a directory twice shouldn't count its files twice
+		if self.child( dir, name ).is_some()
+		{
+			return;
+		}
+
+		let index = self.entries.len();
+		self.entries.push( Entry { name: name.to_string(), parent: Some( dir ), kind, size } );
+		if let Kind::Dir( children ) = &mut self.entries[ dir ].kind
+		{
+			children.push( index );
+			self.names.entry( dir ).or_default().insert( name.to_string(), index );
+		}
+	}
+
+	// Since entries come after their directory, going backwards sees every
+	// directory's contents before the directory itself
+	fn total_sizes( self: &mut Self )
+	{
+		for i in ( 1..self.entries.len() ).rev()
+		{
+			let size = self.entries[ i ].size;
+			if let Some( parent ) = self.entries[ i ].parent
+			{
+				self.entries[ parent ].size += size;
+			}
+		}
+	}
+
+	pub fn total_size( self: &Self ) -> usize
+	{
+		return self.entries[ ROOT ].size;
+	}
+
+	pub fn dirs( self: &Self ) -> impl Iterator< Item = &Entry >
+	{
+		return self.entries.iter().filter( | entry | entry.is_dir() );
+	}
+
+	// Every directory holding at most `max_size`
+	pub fn dirs_at_most( self: &Self, max_size: usize ) -> impl Iterator< Item = &Entry >
+	{
+		return self.dirs().filter( move | dir | dir.size <= max_size );
+	}
+
+	// The size of the smallest directory holding at least `min_size`
+	pub fn smallest_dir_at_least( self: &Self, min_size: usize ) -> Option< usize >
+	{
+		return self.dirs().map( | dir | dir.size ).filter( | size | *size >= min_size ).min();
+	}
+
+	// The full path of an entry, with a slash after directories
+	pub fn path( self: &Self, index: usize ) -> String
+	{
+		let mut names: Vec< &str > = Vec::new();
+		let mut i = index;
+		while let Some( parent ) = self.entries[ i ].parent
+		{
+			names.push( &self.entries[ i ].name );
+			i = parent;
+		}
+		names.reverse();
+
+		let mut path = format!( "/{}", names.join( "/" ) );
+		if index != ROOT && self.entries[ index ].is_dir()
+		{
+			path.push( '/' );
+		}
+		return path;
+	}
+}
+
+// The tree as an indented listing, with the size of everything
+pub fn dump( fs: &FileSystem ) -> String
+{
+	let mut out = String::new();
+
+	// entry and depth, with the next one to print on top
+	let mut todo: Vec< ( usize, usize ) > = vec![ ( ROOT, 0 ) ];
+	while let Some( ( i, depth ) ) = todo.pop()
+	{
+		let entry = &fs.entries[ i ];
+		let indent = depth * 3;
+		match &entry.kind
+		{
+			Kind::Dir( children ) =>
+			{
+				let slash = if i == ROOT { "" } else { "/" };
+				out.push_str( &format!( "{:>indent$} - {}{} ({})\n", "", entry.name, slash, entry.size ) );
+				todo.extend( children.iter().rev().map( | child | ( *child, depth + 1 ) ) );
+			},
+			Kind::File => out.push_str( &format!( "{:>indent$}  - {} ({})\n", "", entry.name, entry.size ) ),
+		}
+	}
+	return out;
+}
+
+pub fn parse( input: &str ) -> Result< FileSystem, ParseError >
+{
+	let mut fs = FileSystem::new();
+	let mut cd = ROOT;
+	let mut processing_dir = false;
+
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		if cur_line.len() == 0
+		{
+			break;
+		}
+
+		let mut scanner = Scanner::new( i + 1, cur_line );
+
+		if processing_dir
+		{
+			if scanner.optional( "dir " )
+			{
+				let name = scanner.rest();
+				fs.add( cd, name, Kind::Dir( Vec::new() ), 0 );
+				continue;
+			}
+			else if cur_line.starts_with( '$' )
+			{
+				processing_dir = false;
+			}
+			else
+			{
+				let size = scanner.number::<usize>()?;
+				scanner.literal( " " )?;
+				let name = scanner.rest();
+				fs.add( cd, name, Kind::File, size );
+				continue;
+			}
+		}
+
+		scanner.literal( "$ " )?;
+		match scanner.one_of( &[ "cd ", "ls" ] )?
+		{
+			0 =>
+			{
+				let column = scanner.column();
+				let target = scanner.rest();
+				if target == "/"
+				{
+					cd = ROOT;
+				}
+				else if target == ".."
+				{
+					match fs.entries[ cd ].parent
+					{
+						Some( parent ) => cd = parent,
+						None => return Err( scanner.error_at( column, "a directory below /" ) ),
+					}
+				}
+				else
+				{
+					match fs.child( cd, target )
+					{
+						Some( dir ) if fs.entries[ dir ].is_dir() => cd = dir,
+						_ => return Err( scanner.error_at( column, "a directory listed by ls" ) ),
+					}
+				}
+			},
+			_ =>
+			{
+				scanner.end()?;
+				processing_dir = true;
+			},
+		}
+	}
+
+	fs.total_sizes();
+	return Ok( fs );
+}
+
+const DRIVE_SIZE: usize = 70000000;
+const UPDATE_SIZE: usize = 30000000;
+
+pub fn part1( fs: &FileSystem ) -> Option< usize >
+{
+	return Some( fs.dirs_at_most( 100000 ).map( | dir | dir.size ).sum() );
+}
+
+pub fn part2( fs: &FileSystem ) -> Option< usize >
+{
+	let free_space = DRIVE_SIZE.checked_sub( fs.total_size() )?;
+	let space_needed = UPDATE_SIZE.saturating_sub( free_space );
+	return fs.smallest_dir_at_least( space_needed );
+}
@@ -0,0 +1,171 @@
+
+use aoc_common::Grid;
+use aoc_common::ParseError;
+use std::cmp;
+
+pub type Trees = Grid< i32 >;
+
+pub fn parse( input: &str ) -> Result< Trees, ParseError >
+{
+	return Grid::parse( input, "a tree height from 0 to 9", | _x, _y, c |
+	{
+		c.to_digit( 10 ).map( | h | h as i32 )
+	} );
+}
+
+pub fn part1( forest: &Trees ) -> Option< usize >
+{
+	let ( trees, width, height ) = ( forest, forest.width(), forest.height() );
+
+	let mut views: Grid< i32 > = Grid::new( width, height, 0 );
+
+
+	// from top
+	for x in 0..width
+	{
+		let mut max: i32 = -1;
+		for y in 0..height
+		{
+			let h = trees[ ( x, y ) ];
+			if h > max
+			{
+				max = h;
+
+				views[ ( x, y ) ] += 1;
+			}
+		}
+	}
+
+	// from left
+	for y in 0..height
+	{
+		let mut max: i32 = -1;
+		for x in 0..width
+		{
+			let h = trees[ ( x, y ) ];
+			if h > max
+			{
+				max = h;
+
+				views[ ( x, y ) ] += 1;
+			}
+		}
+	}
+
+	// from bottom
+	for x in 0..width
+	{
+		let mut max: i32 = -1;
+		for y in ( 0..height ).rev()
+		{
+			let h = trees[ ( x, y ) ];
+			if h > max
+			{
+				max = h;
+
+				views[ ( x, y ) ] += 1;
+			}
+		}
+	}
+
+	// from right
+	for y in 0..height
+	{
+		let mut max: i32 = -1;
+		for x in ( 0..width ).rev()
+		{
+			let h = trees[ ( x, y ) ];
+			if h > max
+			{
+				max = h;
+
+				views[ ( x, y ) ] += 1;
+			}
+		}
+	}
+
+	let mut visible = 0;
+	for view in views.iter()
+	{
+		if view > &0
+		{
+			visible += 1;
+		}
+	}
+	return Some( visible );
+}
+
+pub fn part2( forest: &Trees ) -> Option< i32 >
+{
+	let ( trees, width, height ) = ( forest, forest.width(), forest.height() );
+
+	// scenic 
+	let mut most_scenic = 0;
+	for x in 0..width
+	{
+		for y in 0..height
+		{
+			let h = trees[ ( x, y ) ];
+
+			// left
+			let mut left = 0;
+			if x > 0
+			{
+				for tx in (0..x).rev()
+				{
+					let th = trees[ ( tx, y ) ];
+					left+=1;
+					if th >= h 
+					{
+						break;
+					}
+				}
+			}
+
+			// right
+			let mut right = 0;
+			for tx in (x+1)..width
+			{
+				let th = trees[ ( tx, y ) ];
+				right+=1;
+				if th >= h 
+				{
+					break;
+				}
+			}
+
+			// up
+			let mut up = 0;
+			if y > 0
+			{
+				for ty in (0..y ).rev()
+				{
+					let th = trees[ ( x, ty ) ];
+					up+= 1;
+					if th >= h 
+					{
+						break;
+					}
+				}
+			}
+
+			// down
+			let mut down = 0;
+			for ty in (y+1)..height
+			{
+				let th = trees[ ( x, ty ) ];
+				down+= 1;
+				if th >= h 
+				{
+					break;
+				}
+			}
+
+			let scenic = left * right * up * down;
+
+			most_scenic = cmp::max( scenic, most_scenic );
+		}
+	}
+
+	return Some( most_scenic );
+}
@@ -0,0 +1,118 @@
+use aoc_common::ParseError;
+use aoc_common::Scanner;
+use std::collections::HashSet;
+
+#[derive(Eq,PartialEq,Clone,Hash,Debug)]
+struct Point
+{
+	x: i32,
+	y: i32,
+}
+
+fn move_tail( h: &Point, t: &mut Point )
+{
+	let dx = t.x - h.x;
+	let dy = t.y - h.y;
+	if dx.abs() <= 1 && dy.abs() <= 1
+	{
+		// if we're already adjacent, nothing to do here.
+		return;
+	}
+
+	if dx < 0
+	{
+		t.x += 1;
+	}
+	else if dx > 0
+	{
+		t.x -= 1;
+	}
+
+	if dy < 0
+	{
+		t.y += 1;
+	}
+	else if dy > 0
+	{
+		t.y -= 1;
+	}
+}
+
+#[derive(Eq,PartialEq,Clone,Copy,Debug)]
+pub struct Motion
+{
+	dx: i32,
+	dy: i32,
+	n: i32,
+}
+
+pub fn parse( input: &str ) -> Result< Vec< Motion >, ParseError >
+{
+	let mut motions: Vec< Motion > = Vec::new();
+	for ( i, cur_line ) in input.lines().enumerate()
+	{
+		if cur_line.len() == 0
+		{
+			break;
+		}
+		
+		let mut dx = 0;
+		let mut dy = 0;
+		let mut scanner = Scanner::new( i + 1, cur_line );
+		match scanner.one_of( &[ "U ", "D ", "L ", "R " ] )?
+		{
+			0 => dy = 1,
+			1 => dy = -1,
+			2 => dx = -1,
+			_ => dx = 1,
+		}	
+		let n = scanner.number::<i32>()?;
+		scanner.end()?;
+
+		motions.push( Motion { dx, dy, n } );
+	}
+	return Ok( motions );
+}
+
+fn tail_visits( motions: &[ Motion ], knot_count: usize ) -> usize
+{
+	let mut visited: HashSet<Point> = HashSet::new();
+
+	let mut rope: Vec<Point> = Vec::new();
+
+	for _i in 0..knot_count
+	{
+		rope.push( Point { x: 0, y: 0 } );
+	}
+	visited.insert( rope[0].clone() );
+
+	for m in motions
+	{
+		for _i in 0..m.n
+		{
+			rope[0].x += m.dx;
+			rope[0].y += m.dy;
+			
+			for j in 1..rope.len()
+			{
+				let h = rope[ j - 1 ].clone();
+				move_tail( &h, &mut rope[ j ]);
+			}
+			//println!( "{:?}", rope );
+
+			visited.insert( rope.last().unwrap().clone() );
+		}
+	}
+
+	return visited.len();
+}
+
+pub fn part1( motions: &[ Motion ] ) -> Option< usize >
+{
+	return Some( tail_visits( motions, 2 ) );
+}
+
+pub fn part2( motions: &[ Motion ] ) -> Option< usize >
+{
+	return Some( tail_visits( motions, 10 ) );
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Where a simulation starts repeating itself: the state after `start`
+// steps comes round again every `period` steps after that
+#[derive(Eq,PartialEq,Copy,Clone,Hash,Debug)]
+pub struct Cycle
+{
+	pub start: u64,
+	pub period: u64,
+}
+
+// Value of `accumulator` after `steps` calls to `step`, for step counts far
+// too big to simulate.
+//
+// The simulation runs until `key` gives a key it has seen before. From then
+// on it assumes every trip round the cycle adds the same amount to the
+// accumulator, so the key has to capture everything that decides how the
+// accumulator changes, e.g. the shape of the top of a tower rather than
+// its height.
+pub fn extrapolate< S, K, F, G, H >( state: S, steps: u64, step: F, key: G, accumulator: H ) -> i64
+	where K: Eq + Hash, F: FnMut( &mut S ), G: Fn( &S ) -> K, H: Fn( &S ) -> i64
+{
+	let ( values, cycle ) = simulate( state, steps, step, key, accumulator );
+
+	let cycle = match cycle
+	{
+		Some( cycle ) => cycle,
+		None => return values[ steps as usize ],
+	};
+
+	let start = cycle.start as usize;
+	let end = start + cycle.period as usize;
+	let per_cycle = values[ end ] - values[ start ];
+
+	let cycles = ( steps - cycle.start ) / cycle.period;
+	let leftover = ( ( steps - cycle.start ) % cycle.period ) as usize;
+
+	return values[ start + leftover ] + cycles as i64 * per_cycle;
+}
+
+// Run until `steps` is reached or a key repeats, returning the accumulator
+// after every step so far, starting with the initial state, and the cycle
+// if one turned up
+pub fn simulate< S, K, F, G, H >( mut state: S, steps: u64, mut step: F, key: G, accumulator: H ) -> ( Vec< i64 >, Option< Cycle > )
+	where K: Eq + Hash, F: FnMut( &mut S ), G: Fn( &S ) -> K, H: Fn( &S ) -> i64
+{
+	let mut seen: HashMap< K, u64 > = HashMap::new();
+	let mut values: Vec< i64 > = vec![ accumulator( &state ) ];
+
+	for n in 0..steps
+	{
+		if let Some( first ) = seen.insert( key( &state ), n )
+		{
+			return ( values, Some( Cycle { start: first, period: n - first } ) );
+		}
+
+		step( &mut state );
+		values.push( accumulator( &state ) );
+	}
+
+	return ( values, None );
+}
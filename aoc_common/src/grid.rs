@@ -0,0 +1,293 @@
+use std::fmt;
+use std::ops::Index;
+use std::ops::IndexMut;
+
+use crate::ParseError;
+
+// Offsets to the squares sharing an edge, then the ones sharing a corner
+const NEIGHBORS_4: [ ( isize, isize ); 4 ] = [ ( 0, -1 ), ( 1, 0 ), ( 0, 1 ), ( -1, 0 ) ];
+const NEIGHBORS_8: [ ( isize, isize ); 8 ] = [ ( 0, -1 ), ( 1, -1 ), ( 1, 0 ), ( 1, 1 ), ( 0, 1 ), ( -1, 1 ), ( -1, 0 ), ( -1, -1 ) ];
+
+// A width x height block of cells stored row by row, with 0,0 at the top
+// left like the puzzle inputs. get() and set() check bounds; indexing
+// with ( x, y ) panics outside the grid.
+#[derive(Eq,PartialEq,Clone,Hash,Debug)]
+pub struct Grid< T >
+{
+	width: usize,
+	height: usize,
+	cells: Vec< T >,
+}
+
+impl< T: Clone > Grid< T >
+{
+	pub fn new( width: usize, height: usize, fill: T ) -> Grid< T >
+	{
+		let mut cells: Vec< T > = Vec::new();
+		cells.resize( width * height, fill );
+		return Grid { width, height, cells };
+	}
+
+	// Build a grid from a map of characters, one row per line, stopping at
+	// the first blank line. `cell` turns the character at x, y into a cell,
+	// or returns None if it doesn't belong in the map, in which case the
+	// error says `expected`. Every row has to be as wide as the first.
+	pub fn parse< F >( input: &str, expected: &str, mut cell: F ) -> Result< Grid< T >, ParseError >
+		where F: FnMut( usize, usize, char ) -> Option< T >
+	{
+		let mut width = 0;
+		let mut height = 0;
+		let mut cells: Vec< T > = Vec::new();
+
+		for ( y, cur_line ) in input.lines().enumerate()
+		{
+			if cur_line.len() == 0
+			{
+				break;
+			}
+
+			if y == 0
+			{
+				width = cur_line.chars().count();
+			}
+
+			let mut x = 0;
+			for c in cur_line.chars()
+			{
+				if x == width
+				{
+					return Err( ParseError::new( y + 1, x + 1, &format!( "a row {} squares wide", width ) ) );
+				}
+				match cell( x, y, c )
+				{
+					Some( value ) => cells.push( value ),
+					None => return Err( ParseError::new( y + 1, x + 1, expected ) ),
+				}
+				x += 1;
+			}
+
+			if x != width
+			{
+				return Err( ParseError::new( y + 1, x + 1, &format!( "a row {} squares wide", width ) ) );
+			}
+			height += 1;
+		}
+
+		return Ok( Grid { width, height, cells } );
+	}
+
+	// Turn rows into columns, so x, y ends up at y, x
+	pub fn transpose( self: &mut Self )
+	{
+		// 1 2 3    1 4 7
+		// 4 5 6 => 2 5 8
+		// 7 8 9    3 6 9
+		let mut cells: Vec< T > = Vec::new();
+		cells.reserve( self.cells.len() );
+		for x in 0..self.width
+		{
+			for y in 0..self.height
+			{
+				cells.push( self.cells[ x + y * self.width ].clone() );
+			}
+		}
+
+		self.cells = cells;
+		( self.width, self.height ) = ( self.height, self.width );
+	}
+
+	pub fn flip_vertical( self: &mut Self )
+	{
+		// 1 2 3    7 8 9
+		// 4 5 6 => 4 5 6
+		// 7 8 9    1 2 3
+		for y in 0..( self.height / 2 )
+		{
+			let other = self.height - 1 - y;
+			for x in 0..self.width
+			{
+				self.cells.swap( x + y * self.width, x + other * self.width );
+			}
+		}
+	}
+
+	pub fn flip_horizontal( self: &mut Self )
+	{
+		// 1 2 3    3 2 1
+		// 4 5 6 => 6 5 4
+		// 7 8 9    9 8 7
+		for y in 0..self.height
+		{
+			let row = &mut self.cells[ y * self.width..( y + 1 ) * self.width ];
+			row.reverse();
+		}
+	}
+
+	pub fn rotate_left( self: &mut Self )
+	{
+		// 1 2 3    3 6 9
+		// 4 5 6 => 2 5 8
+		// 7 8 9    1 4 7
+
+		// This is a transpose followed by a vertical flip
+		self.transpose();
+		self.flip_vertical();
+	}
+
+	pub fn rotate_right( self: &mut Self )
+	{
+		// 1 2 3    7 4 1
+		// 4 5 6 => 8 5 2
+		// 7 8 9    9 6 3
+
+		// this is a transpose, followed by a horizontal flip
+		self.transpose();
+		self.flip_horizontal();
+	}
+
+	// Grow the grid by `count` rows of `fill` along the bottom
+	pub fn add_rows( self: &mut Self, count: usize, fill: T )
+	{
+		self.height += count;
+		self.cells.resize( self.width * self.height, fill );
+	}
+}
+
+impl< T > Grid< T >
+{
+	pub fn width( self: &Self ) -> usize
+	{
+		return self.width;
+	}
+
+	pub fn height( self: &Self ) -> usize
+	{
+		return self.height;
+	}
+
+	pub fn in_bounds( self: &Self, x: isize, y: isize ) -> bool
+	{
+		return x >= 0 && y >= 0 && ( x as usize ) < self.width && ( y as usize ) < self.height;
+	}
+
+	pub fn get( self: &Self, x: usize, y: usize ) -> Option< &T >
+	{
+		if x >= self.width || y >= self.height
+		{
+			return None;
+		}
+		return Some( &self.cells[ x + y * self.width ] );
+	}
+
+	pub fn get_mut( self: &mut Self, x: usize, y: usize ) -> Option< &mut T >
+	{
+		if x >= self.width || y >= self.height
+		{
+			return None;
+		}
+		return Some( &mut self.cells[ x + y * self.width ] );
+	}
+
+	// Returns false, leaving the grid alone, if x, y is outside it
+	pub fn set( self: &mut Self, x: usize, y: usize, value: T ) -> bool
+	{
+		return match self.get_mut( x, y )
+		{
+			Some( cell ) =>
+			{
+				*cell = value;
+				true
+			},
+			None => false,
+		};
+	}
+
+	// x + dx, y + dy if that's still inside the grid
+	pub fn offset( self: &Self, x: usize, y: usize, dx: isize, dy: isize ) -> Option< ( usize, usize ) >
+	{
+		let nx = x as isize + dx;
+		let ny = y as isize + dy;
+		if !self.in_bounds( nx, ny )
+		{
+			return None;
+		}
+		return Some( ( nx as usize, ny as usize ) );
+	}
+
+	// Squares sharing an edge with x, y, clockwise from the one above
+	pub fn neighbors4( self: &Self, x: usize, y: usize ) -> impl Iterator< Item = ( usize, usize ) > + '_
+	{
+		return NEIGHBORS_4.iter().filter_map( move | ( dx, dy ) | self.offset( x, y, *dx, *dy ) );
+	}
+
+	// Squares sharing an edge or a corner with x, y, clockwise from the one above
+	pub fn neighbors8( self: &Self, x: usize, y: usize ) -> impl Iterator< Item = ( usize, usize ) > + '_
+	{
+		return NEIGHBORS_8.iter().filter_map( move | ( dx, dy ) | self.offset( x, y, *dx, *dy ) );
+	}
+
+	// Every x, y in the grid, row by row
+	pub fn positions( self: &Self ) -> impl Iterator< Item = ( usize, usize ) >
+	{
+		let width = self.width;
+		return ( 0..self.width * self.height ).map( move | i | ( i % width, i / width ) );
+	}
+
+	pub fn row( self: &Self, y: usize ) -> &[ T ]
+	{
+		return &self.cells[ y * self.width..( y + 1 ) * self.width ];
+	}
+
+	pub fn column( self: &Self, x: usize ) -> impl DoubleEndedIterator< Item = &T > + '_
+	{
+		return ( 0..self.height ).map( move | y | &self.cells[ x + y * self.width ] );
+	}
+
+	pub fn rows( self: &Self ) -> impl DoubleEndedIterator< Item = &[ T ] > + '_
+	{
+		return ( 0..self.height ).map( move | y | self.row( y ) );
+	}
+
+	pub fn iter( self: &Self ) -> std::slice::Iter< '_, T >
+	{
+		return self.cells.iter();
+	}
+}
+
+impl< T > Index< ( usize, usize ) > for Grid< T >
+{
+	type Output = T;
+
+	fn index( self: &Self, ( x, y ): ( usize, usize ) ) -> &T
+	{
+		assert!( x < self.width && y < self.height, "{},{} is outside a {}x{} grid", x, y, self.width, self.height );
+		return &self.cells[ x + y * self.width ];
+	}
+}
+
+impl< T > IndexMut< ( usize, usize ) > for Grid< T >
+{
+	fn index_mut( self: &mut Self, ( x, y ): ( usize, usize ) ) -> &mut T
+	{
+		assert!( x < self.width && y < self.height, "{},{} is outside a {}x{} grid", x, y, self.width, self.height );
+		return &mut self.cells[ x + y * self.width ];
+	}
+}
+
+// One line per row, each cell rendered with its own Display
+impl< T: fmt::Display > fmt::Display for Grid< T >
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		for row in self.rows()
+		{
+			for cell in row
+			{
+				write!( f, "{}", cell )?;
+			}
+			f.write_str( "\n" )?;
+		}
+		Ok( () )
+	}
+}
+
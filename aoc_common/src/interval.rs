@@ -0,0 +1,258 @@
+use std::cmp;
+
+// An inclusive run of integers, start..=end, like the section assignments
+// and sensor coverage in the puzzles
+#[derive(Eq,PartialEq,Ord,PartialOrd,Copy,Clone,Hash,Debug)]
+pub struct Interval
+{
+	pub start: i64,
+	pub end: i64,
+}
+
+impl Interval
+{
+	pub fn new( start: i64, end: i64 ) -> Interval
+	{
+		assert!( start <= end, "interval {}..={} ends before it starts", start, end );
+		return Interval { start, end };
+	}
+
+	pub fn len( self: &Self ) -> i64
+	{
+		return self.end - self.start + 1;
+	}
+
+	pub fn contains( self: &Self, value: i64 ) -> bool
+	{
+		return self.start <= value && value <= self.end;
+	}
+
+	// true if every value in `other` is also in this one
+	pub fn contains_interval( self: &Self, other: &Interval ) -> bool
+	{
+		return self.start <= other.start && other.end <= self.end;
+	}
+
+	pub fn overlaps( self: &Self, other: &Interval ) -> bool
+	{
+		return !( self.start > other.end || other.start > self.end );
+	}
+
+	pub fn intersection( self: &Self, other: &Interval ) -> Option< Interval >
+	{
+		if !self.overlaps( other )
+		{
+			return None;
+		}
+		return Some( Interval::new( cmp::max( self.start, other.start ), cmp::min( self.end, other.end ) ) );
+	}
+}
+
+// A set of integers kept as sorted, non-overlapping intervals. Intervals
+// that touch are merged, so 1..=3 and 4..=6 become 1..=6.
+#[derive(Eq,PartialEq,Clone,Hash,Debug,Default)]
+pub struct IntervalSet
+{
+	intervals: Vec< Interval >,
+}
+
+impl IntervalSet
+{
+	pub fn new() -> IntervalSet
+	{
+		return IntervalSet { intervals: Vec::new() };
+	}
+
+	pub fn intervals( self: &Self ) -> &[ Interval ]
+	{
+		return &self.intervals;
+	}
+
+	pub fn is_empty( self: &Self ) -> bool
+	{
+		return self.intervals.len() == 0;
+	}
+
+	// How many values are in the set
+	pub fn len( self: &Self ) -> i64
+	{
+		return self.intervals.iter().map( | i | i.len() ).sum();
+	}
+
+	pub fn insert( self: &mut Self, interval: Interval )
+	{
+		// everything that ends before the new one starts stays put, as does
+		// everything that starts after it ends. Whatever's between gets
+		// merged into it.
+		let first = self.intervals.partition_point( | i | i.end < interval.start - 1 );
+		let last = self.intervals.partition_point( | i | i.start <= interval.end + 1 );
+
+		let mut merged = interval;
+		if first < last
+		{
+			merged.start = cmp::min( merged.start, self.intervals[ first ].start );
+			merged.end = cmp::max( merged.end, self.intervals[ last - 1 ].end );
+		}
+		self.intervals.splice( first..last, [ merged ] );
+	}
+
+	pub fn contains( self: &Self, value: i64 ) -> bool
+	{
+		return self.contains_interval( &Interval::new( value, value ) );
+	}
+
+	pub fn contains_interval( self: &Self, interval: &Interval ) -> bool
+	{
+		// merged intervals never touch, so it has to fit inside just one
+		let i = self.intervals.partition_point( | i | i.end < interval.start );
+		return i < self.intervals.len() && self.intervals[ i ].contains_interval( interval );
+	}
+
+	pub fn overlaps( self: &Self, interval: &Interval ) -> bool
+	{
+		let i = self.intervals.partition_point( | i | i.end < interval.start );
+		return i < self.intervals.len() && self.intervals[ i ].overlaps( interval );
+	}
+
+	pub fn union( self: &Self, other: &IntervalSet ) -> IntervalSet
+	{
+		let mut out = self.clone();
+		for interval in &other.intervals
+		{
+			out.insert( *interval );
+		}
+		return out;
+	}
+
+	pub fn intersection( self: &Self, other: &IntervalSet ) -> IntervalSet
+	{
+		let mut out = IntervalSet::new();
+		let ( mut i, mut j ) = ( 0, 0 );
+		while i < self.intervals.len() && j < other.intervals.len()
+		{
+			let ( a, b ) = ( self.intervals[ i ], other.intervals[ j ] );
+			if let Some( both ) = a.intersection( &b )
+			{
+				out.intervals.push( both );
+			}
+
+			// move past whichever finishes first
+			if a.end < b.end
+			{
+				i += 1;
+			}
+			else
+			{
+				j += 1;
+			}
+		}
+		return out;
+	}
+
+	// Values in this set that aren't in `other`
+	pub fn difference( self: &Self, other: &IntervalSet ) -> IntervalSet
+	{
+		let mut out = IntervalSet::new();
+		let mut j = 0;
+		for interval in &self.intervals
+		{
+			let mut start = interval.start;
+
+			// skip anything in other that's entirely before this interval
+			while j < other.intervals.len() && other.intervals[ j ].end < start
+			{
+				j += 1;
+			}
+
+			let mut k = j;
+			while k < other.intervals.len() && other.intervals[ k ].start <= interval.end
+			{
+				let cut = other.intervals[ k ];
+				if cut.start > start
+				{
+					out.intervals.push( Interval::new( start, cut.start - 1 ) );
+				}
+				start = cmp::max( start, cut.end + 1 );
+				k += 1;
+			}
+
+			if start <= interval.end
+			{
+				out.intervals.push( Interval::new( start, interval.end ) );
+			}
+		}
+		return out;
+	}
+
+	// The runs of missing values between the first and last values in the set
+	pub fn gaps( self: &Self ) -> Vec< Interval >
+	{
+		let mut gaps: Vec< Interval > = Vec::new();
+		for pair in self.intervals.windows( 2 )
+		{
+			gaps.push( Interval::new( pair[ 0 ].end + 1, pair[ 1 ].start - 1 ) );
+		}
+		return gaps;
+	}
+}
+
+impl From< Interval > for IntervalSet
+{
+	fn from( interval: Interval ) -> IntervalSet
+	{
+		return IntervalSet { intervals: vec![ interval ] };
+	}
+}
+
+impl FromIterator< Interval > for IntervalSet
+{
+	fn from_iter< I: IntoIterator< Item = Interval > >( iter: I ) -> IntervalSet
+	{
+		let mut set = IntervalSet::new();
+		for interval in iter
+		{
+			set.insert( interval );
+		}
+		return set;
+	}
+}
+
+// How many of `intervals` cover each value, as runs of equal depth in
+// order. Values nothing covers are left out, and neighboring runs always
+// have different depths.
+pub fn coverage( intervals: &[ Interval ] ) -> Vec< ( Interval, usize ) >
+{
+	// +1 where each interval starts, -1 just past where it ends
+	let mut edges: Vec< ( i64, i64 ) > = Vec::new();
+	for interval in intervals
+	{
+		edges.push( ( interval.start, 1 ) );
+		edges.push( ( interval.end + 1, -1 ) );
+	}
+	edges.sort();
+
+	let mut runs: Vec< ( Interval, usize ) > = Vec::new();
+	let mut depth: i64 = 0;
+	let mut i = 0;
+	while i < edges.len()
+	{
+		let at = edges[ i ].0;
+		while i < edges.len() && edges[ i ].0 == at
+		{
+			depth += edges[ i ].1;
+			i += 1;
+		}
+
+		// the run goes on until the next edge, if the depth stays above 0
+		if depth > 0 && i < edges.len()
+		{
+			let run = Interval::new( at, edges[ i ].0 - 1 );
+			match runs.last_mut()
+			{
+				Some( ( last, last_depth ) ) if last.end + 1 == run.start && *last_depth == depth as usize => last.end = run.end,
+				_ => runs.push( ( run, depth as usize ) ),
+			}
+		}
+	}
+	return runs;
+}
@@ -0,0 +1,68 @@
+use std::fs;
+use std::io;
+use std::process;
+
+pub mod cycle;
+mod grid;
+mod interval;
+mod parse;
+pub mod search;
+
+pub use grid::Grid;
+pub use interval::coverage;
+pub use interval::Interval;
+pub use interval::IntervalSet;
+pub use parse::ParseError;
+pub use parse::Scanner;
+
+// Read all of stdin and run a day's parser over it. The day binaries have
+// nothing useful to do with bad input, so report it and exit.
+pub fn parse_stdin< T, F >( parse: F ) -> T
+	where F: FnOnce( &str ) -> Result< T, ParseError >
+{
+	let input = match io::read_to_string( io::stdin() )
+	{
+		Ok( input ) => input,
+		Err( e ) =>
+		{
+			eprintln!( "failed to read input: {}", e );
+			process::exit( 1 );
+		}
+	};
+
+	return match parse( &input )
+	{
+		Ok( parsed ) => parsed,
+		Err( e ) =>
+		{
+			eprintln!( "{}", e );
+			process::exit( 1 );
+		}
+	};
+}
+
+// Read and parse a file named on the command line, like a rules file that
+// goes with the puzzle input. Errors are reported with the path and exit.
+pub fn parse_file< T, F >( path: &str, parse: F ) -> T
+	where F: FnOnce( &str ) -> Result< T, ParseError >
+{
+	let text = match fs::read_to_string( path )
+	{
+		Ok( text ) => text,
+		Err( e ) =>
+		{
+			eprintln!( "failed to read {}: {}", path, e );
+			process::exit( 1 );
+		}
+	};
+
+	return match parse( &text )
+	{
+		Ok( parsed ) => parsed,
+		Err( e ) =>
+		{
+			eprintln!( "{}: {}", path, e );
+			process::exit( 1 );
+		}
+	};
+}
@@ -0,0 +1,161 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Error returned by every day's parse(). Lines and columns are 1-based so
+// they match what an editor shows for the input file. `expected` describes
+// the token that should have been at that position, e.g. "a number" or
+// "`move`".
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct ParseError
+{
+	pub line: usize,
+	pub column: usize,
+	pub expected: String,
+}
+
+impl ParseError
+{
+	pub fn new( line: usize, column: usize, expected: &str ) -> ParseError
+	{
+		return ParseError
+		{
+			line,
+			column,
+			expected: expected.to_string(),
+		};
+	}
+}
+
+impl fmt::Display for ParseError
+{
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result
+	{
+		f.write_fmt( format_args!( "line {}, column {}: expected {}", self.line, self.column, self.expected ) )
+	}
+}
+
+impl Error for ParseError {}
+
+// Walks a single input line left to right, so the day parsers can pull
+// out literals, names and numbers and get a ParseError pointing at the
+// right column when something doesn't match.
+#[derive(Clone,Debug)]
+pub struct Scanner< 'a >
+{
+	line: usize,
+	text: &'a str,
+	pos: usize,
+}
+
+impl< 'a > Scanner< 'a >
+{
+	// `line` is the 1-based line number used in errors
+	pub fn new( line: usize, text: &'a str ) -> Scanner< 'a >
+	{
+		return Scanner { line, text, pos: 0 };
+	}
+
+	pub fn column( self: &Self ) -> usize
+	{
+		return self.pos + 1;
+	}
+
+	pub fn rest( self: &Self ) -> &'a str
+	{
+		return &self.text[ self.pos.. ];
+	}
+
+	pub fn is_done( self: &Self ) -> bool
+	{
+		return self.pos >= self.text.len();
+	}
+
+	pub fn error( self: &Self, expected: &str ) -> ParseError
+	{
+		return ParseError::new( self.line, self.column(), expected );
+	}
+
+	// For values that scanned fine but turned out to be wrong, pointing
+	// back at the column where they started
+	pub fn error_at( self: &Self, column: usize, expected: &str ) -> ParseError
+	{
+		return ParseError::new( self.line, column, expected );
+	}
+
+	// Consume `literal` exactly
+	pub fn literal( self: &mut Self, literal: &str ) -> Result< (), ParseError >
+	{
+		if !self.rest().starts_with( literal )
+		{
+			return Err( self.error( &format!( "`{}`", literal.trim() ) ) );
+		}
+
+		self.pos += literal.len();
+		return Ok( () );
+	}
+
+	// Consume `literal` if it is next, without failing if it isn't
+	pub fn optional( self: &mut Self, literal: &str ) -> bool
+	{
+		return self.literal( literal ).is_ok();
+	}
+
+	// Consume one of `literals`, returning the index of the one that matched
+	pub fn one_of( self: &mut Self, literals: &[ &str ] ) -> Result< usize, ParseError >
+	{
+		for i in 0..literals.len()
+		{
+			if self.optional( literals[ i ] )
+			{
+				return Ok( i );
+			}
+		}
+
+		let names: Vec< String > = literals.iter().map( | l | format!( "`{}`", l.trim() ) ).collect();
+		return Err( self.error( &format!( "one of {}", names.join( ", " ) ) ) );
+	}
+
+	// Consume a run of letters and digits
+	pub fn word( self: &mut Self ) -> Result< &'a str, ParseError >
+	{
+		let len = self.rest().find( | c: char | !c.is_ascii_alphanumeric() ).unwrap_or( self.rest().len() );
+		if len == 0
+		{
+			return Err( self.error( "a name" ) );
+		}
+
+		let word = &self.rest()[ ..len ];
+		self.pos += len;
+		return Ok( word );
+	}
+
+	// Consume a decimal number with an optional leading minus sign
+	pub fn number< T: FromStr >( self: &mut Self ) -> Result< T, ParseError >
+	{
+		let rest = self.rest();
+		let sign = if rest.starts_with( '-' ) { 1 } else { 0 };
+		let digits = rest[ sign.. ].find( | c: char | !c.is_ascii_digit() ).unwrap_or( rest.len() - sign );
+
+		return match rest[ ..sign + digits ].parse::< T >()
+		{
+			Ok( n ) if digits > 0 =>
+			{
+				self.pos += sign + digits;
+				Ok( n )
+			},
+			_ => Err( self.error( "a number" ) ),
+		};
+	}
+
+	// Fail unless the whole line has been consumed
+	pub fn end( self: &Self ) -> Result< (), ParseError >
+	{
+		if !self.is_done()
+		{
+			return Err( self.error( "end of line" ) );
+		}
+
+		return Ok( () );
+	}
+}
@@ -0,0 +1,186 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+// A graph to search, described by what can be reached from each state.
+// The space holds whatever the states need to look up (a map, a
+// blueprint...) so the states themselves can stay small and hashable.
+pub trait SearchSpace
+{
+	type State: Clone + Eq + Hash;
+
+	// Every state one step away, with the cost of taking that step
+	fn successors( self: &Self, state: &Self::State ) -> Vec< ( Self::State, usize ) >;
+
+	fn is_goal( self: &Self, state: &Self::State ) -> bool;
+
+	// Estimate of the cost left to reach a goal. astar() only finds the
+	// best path if this never overestimates. If it also never drops by
+	// more than a step costs, no state gets expanded twice.
+	fn heuristic( self: &Self, _state: &Self::State ) -> usize
+	{
+		return 0;
+	}
+}
+
+// The states from the start to the goal, both included, and what it cost
+// to walk them
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct Path< S >
+{
+	pub states: Vec< S >,
+	pub cost: usize,
+}
+
+// `expanded` counts the states whose successors were looked at, whether
+// or not a path turned up
+#[derive(Eq,PartialEq,Clone,Debug)]
+pub struct SearchResult< S >
+{
+	pub path: Option< Path< S > >,
+	pub expanded: usize,
+}
+
+// Walk parent links back from `end` to rebuild the path
+fn trace< S: Clone >( nodes: &[ ( S, Option< usize >, usize ) ], end: usize ) -> Path< S >
+{
+	let mut states: Vec< S > = Vec::new();
+	let mut i = Some( end );
+	while let Some( n ) = i
+	{
+		states.push( nodes[ n ].0.clone() );
+		i = nodes[ n ].1;
+	}
+	states.reverse();
+
+	return Path { states, cost: nodes[ end ].2 };
+}
+
+// Fewest steps to a goal, ignoring step costs. The path's cost is its
+// number of steps.
+pub fn bfs< P: SearchSpace >( space: &P, start: P::State ) -> SearchResult< P::State >
+{
+	// state, parent, steps so far
+	let mut nodes: Vec< ( P::State, Option< usize >, usize ) > = vec![ ( start.clone(), None, 0 ) ];
+	let mut visited: HashSet< P::State > = HashSet::from( [ start ] );
+	let mut todo: VecDeque< usize > = VecDeque::from( [ 0 ] );
+	let mut expanded = 0;
+
+	while let Some( n ) = todo.pop_front()
+	{
+		if space.is_goal( &nodes[ n ].0 )
+		{
+			return SearchResult { path: Some( trace( &nodes, n ) ), expanded };
+		}
+
+		expanded += 1;
+		for ( next, _ ) in space.successors( &nodes[ n ].0 )
+		{
+			if visited.insert( next.clone() )
+			{
+				nodes.push( ( next, Some( n ), nodes[ n ].2 + 1 ) );
+				todo.push_back( nodes.len() - 1 );
+			}
+		}
+	}
+
+	return SearchResult { path: None, expanded };
+}
+
+// Cheapest path to a goal
+pub fn dijkstra< P: SearchSpace >( space: &P, start: P::State ) -> SearchResult< P::State >
+{
+	return prioritized( space, start, | cost, _ | cost );
+}
+
+// Cheapest path to a goal, looking at the states the heuristic likes first
+pub fn astar< P: SearchSpace >( space: &P, start: P::State ) -> SearchResult< P::State >
+{
+	return prioritized( space, start, | cost, estimate | cost + estimate );
+}
+
+// Always heads for whichever state the heuristic says is closest. Usually
+// quick, but the path it finds isn't necessarily the cheapest.
+pub fn best_first< P: SearchSpace >( space: &P, start: P::State ) -> SearchResult< P::State >
+{
+	return prioritized( space, start, | _, estimate | estimate );
+}
+
+// Expand states in order of `priority( cost so far, heuristic )`, lowest
+// first, keeping only the cheapest way found to each state. A state that
+// turns up again more cheaply after it's been expanded goes back on the
+// list, since a heuristic can put a worse way to it first.
+fn prioritized< P, F >( space: &P, start: P::State, priority: F ) -> SearchResult< P::State >
+	where P: SearchSpace, F: Fn( usize, usize ) -> usize
+{
+	// state, parent, cost so far
+	let mut nodes: Vec< ( P::State, Option< usize >, usize ) > = Vec::new();
+	let mut best_cost: HashMap< P::State, usize > = HashMap::new();
+
+	// lowest priority first; ties go to the node found first
+	let mut todo: BinaryHeap< Reverse< ( usize, usize ) > > = BinaryHeap::new();
+
+	let estimate = space.heuristic( &start );
+	nodes.push( ( start.clone(), None, 0 ) );
+	best_cost.insert( start, 0 );
+	todo.push( Reverse( ( priority( 0, estimate ), 0 ) ) );
+
+	let mut expanded = 0;
+	while let Some( Reverse( ( _, n ) ) ) = todo.pop()
+	{
+		let ( state, _, cost ) = nodes[ n ].clone();
+
+		// skip anything we've since found a cheaper way to
+		if best_cost[ &state ] < cost
+		{
+			continue;
+		}
+
+		if space.is_goal( &state )
+		{
+			return SearchResult { path: Some( trace( &nodes, n ) ), expanded };
+		}
+
+		expanded += 1;
+		for ( next, step_cost ) in space.successors( &state )
+		{
+			let next_cost = cost + step_cost;
+			match best_cost.get( &next )
+			{
+				Some( found_cost ) if *found_cost <= next_cost => continue,
+				_ => {},
+			}
+
+			let estimate = space.heuristic( &next );
+			best_cost.insert( next.clone(), next_cost );
+			nodes.push( ( next, Some( n ), next_cost ) );
+			todo.push( Reverse( ( priority( next_cost, estimate ), nodes.len() - 1 ) ) );
+		}
+	}
+
+	return SearchResult { path: None, expanded };
+}
+
+// Every state reachable from `start`, start included. Goals don't stop
+// the fill.
+pub fn flood< P: SearchSpace >( space: &P, start: P::State ) -> HashSet< P::State >
+{
+	let mut visited: HashSet< P::State > = HashSet::from( [ start.clone() ] );
+	let mut todo: VecDeque< P::State > = VecDeque::from( [ start ] );
+
+	while let Some( state ) = todo.pop_front()
+	{
+		for ( next, _ ) in space.successors( &state )
+		{
+			if visited.insert( next.clone() )
+			{
+				todo.push_back( next );
+			}
+		}
+	}
+
+	return visited;
+}
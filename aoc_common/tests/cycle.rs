@@ -0,0 +1,60 @@
+// Extrapolating simulations that settle into a cycle.
+
+use aoc_common::cycle;
+use aoc_common::cycle::Cycle;
+
+// Counts 0, 1, 2... wrapping back to 2 after 6, and sums the counter as it
+// goes. The first two steps aren't part of the cycle.
+fn counter_sum( steps: u64 ) -> i64
+{
+	return cycle::extrapolate( ( 0, 0 ), steps,
+		| ( counter, sum ) | { *counter = if *counter == 6 { 2 } else { *counter + 1 }; *sum += *counter; },
+		| ( counter, _ ) | *counter,
+		| ( _, sum ) | *sum );
+}
+
+// The same thing the slow way
+fn counter_sum_by_hand( steps: u64 ) -> i64
+{
+	let ( mut counter, mut sum ) = ( 0, 0 );
+	for _ in 0..steps
+	{
+		counter = if counter == 6 { 2 } else { counter + 1 };
+		sum += counter;
+	}
+	return sum;
+}
+
+#[test]
+fn finds_the_cycle()
+{
+	let ( values, cycle ) = cycle::simulate( 0, 100,
+		| counter | *counter = if *counter == 6 { 2 } else { *counter + 1 },
+		| counter | *counter,
+		| counter | *counter );
+
+	assert_eq!( cycle, Some( Cycle { start: 2, period: 5 } ) );
+	assert_eq!( values, vec![ 0, 1, 2, 3, 4, 5, 6, 2 ] );
+}
+
+#[test]
+fn no_cycle_in_time()
+{
+	let ( values, cycle ) = cycle::simulate( 0, 3, | n | *n += 1, | n | *n, | n | *n );
+	assert_eq!( cycle, None );
+	assert_eq!( values, vec![ 0, 1, 2, 3 ] );
+}
+
+#[test]
+fn extrapolates()
+{
+	// before, at and after the cycle shows up, and part way round it
+	for steps in 0..40
+	{
+		assert_eq!( counter_sum( steps ), counter_sum_by_hand( steps ), "after {} steps", steps );
+	}
+
+	// one step to get to the cycle, then 999999999 steps round one that
+	// adds 2+3+4+5+6 = 20 every 5
+	assert_eq!( counter_sum( 1000000000 ), 1 + 199999999 * 20 + 2 + 3 + 4 + 5 );
+}
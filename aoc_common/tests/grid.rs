@@ -0,0 +1,124 @@
+// The shared Grid that the day crates store their maps in.
+
+use aoc_common::Grid;
+use aoc_common::ParseError;
+
+fn digits( input: &str ) -> Grid< u32 >
+{
+	return Grid::parse( input, "a digit", | _x, _y, c | c.to_digit( 10 ) ).unwrap();
+}
+
+#[test]
+fn parse_and_display()
+{
+	let grid = digits( "123\n456\n\nnot part of the map" );
+	assert_eq!( grid.width(), 3 );
+	assert_eq!( grid.height(), 2 );
+	assert_eq!( grid[ ( 2, 1 ) ], 6 );
+	assert_eq!( grid.to_string(), "123\n456\n" );
+}
+
+#[test]
+fn parse_errors()
+{
+	let bad_cell = Grid::parse( "12\n3x\n", "a digit", | _x, _y, c | c.to_digit( 10 ) );
+	assert_eq!( bad_cell, Err( ParseError::new( 2, 2, "a digit" ) ) );
+
+	let short_row = Grid::parse( "123\n45\n", "a digit", | _x, _y, c | c.to_digit( 10 ) );
+	assert_eq!( short_row, Err( ParseError::new( 2, 3, "a row 3 squares wide" ) ) );
+
+	let long_row = Grid::parse( "12\n345\n", "a digit", | _x, _y, c | c.to_digit( 10 ) );
+	assert_eq!( long_row, Err( ParseError::new( 2, 3, "a row 2 squares wide" ) ) );
+}
+
+#[test]
+fn get_and_set_check_bounds()
+{
+	let mut grid = Grid::new( 2, 3, '.' );
+	assert!( grid.set( 1, 2, '#' ) );
+	assert!( !grid.set( 2, 0, '#' ) );
+	assert!( !grid.set( 0, 3, '#' ) );
+
+	assert_eq!( grid.get( 1, 2 ), Some( &'#' ) );
+	assert_eq!( grid.get( 2, 2 ), None );
+	assert_eq!( grid.to_string(), "..\n..\n.#\n" );
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds()
+{
+	let grid = Grid::new( 2, 2, 0 );
+	let _ = grid[ ( 0, 2 ) ];
+}
+
+#[test]
+fn neighbors()
+{
+	let grid = Grid::new( 3, 3, 0 );
+
+	let corner: Vec< ( usize, usize ) > = grid.neighbors4( 0, 0 ).collect();
+	assert_eq!( corner, vec![ ( 1, 0 ), ( 0, 1 ) ] );
+
+	let middle: Vec< ( usize, usize ) > = grid.neighbors4( 1, 1 ).collect();
+	assert_eq!( middle, vec![ ( 1, 0 ), ( 2, 1 ), ( 1, 2 ), ( 0, 1 ) ] );
+
+	let edge: Vec< ( usize, usize ) > = grid.neighbors8( 2, 1 ).collect();
+	assert_eq!( edge, vec![ ( 2, 0 ), ( 2, 2 ), ( 1, 2 ), ( 1, 1 ), ( 1, 0 ) ] );
+
+	assert_eq!( grid.neighbors8( 1, 1 ).count(), 8 );
+}
+
+#[test]
+fn rows_and_columns()
+{
+	let grid = digits( "123\n456\n" );
+	assert_eq!( grid.row( 1 ), &[ 4, 5, 6 ] );
+
+	let column: Vec< u32 > = grid.column( 2 ).copied().collect();
+	assert_eq!( column, vec![ 3, 6 ] );
+
+	let rows: Vec< &[ u32 ] > = grid.rows().rev().collect();
+	assert_eq!( rows, vec![ &[ 4, 5, 6 ][ .. ], &[ 1, 2, 3 ][ .. ] ] );
+
+	let positions: Vec< ( usize, usize ) > = grid.positions().collect();
+	assert_eq!( positions, vec![ ( 0, 0 ), ( 1, 0 ), ( 2, 0 ), ( 0, 1 ), ( 1, 1 ), ( 2, 1 ) ] );
+}
+
+#[test]
+fn add_rows()
+{
+	let mut grid = digits( "123\n456\n" );
+	grid.add_rows( 2, 0 );
+	assert_eq!( grid.height(), 4 );
+	assert_eq!( grid.to_string(), "123\n456\n000\n000\n" );
+}
+
+#[test]
+fn transforms()
+{
+	let grid = digits( "123\n456\n" );
+
+	let mut transposed = grid.clone();
+	transposed.transpose();
+	assert_eq!( transposed.to_string(), "14\n25\n36\n" );
+
+	let mut flipped = grid.clone();
+	flipped.flip_vertical();
+	assert_eq!( flipped.to_string(), "456\n123\n" );
+
+	let mut mirrored = grid.clone();
+	mirrored.flip_horizontal();
+	assert_eq!( mirrored.to_string(), "321\n654\n" );
+
+	let mut left = grid.clone();
+	left.rotate_left();
+	assert_eq!( left.to_string(), "36\n25\n14\n" );
+
+	let mut right = grid.clone();
+	right.rotate_right();
+	assert_eq!( right.to_string(), "41\n52\n63\n" );
+
+	right.rotate_left();
+	assert_eq!( right, grid );
+}
@@ -0,0 +1,115 @@
+// Inclusive integer intervals and the sets built from them.
+
+use aoc_common::Interval;
+use aoc_common::IntervalSet;
+
+fn set( intervals: &[ ( i64, i64 ) ] ) -> IntervalSet
+{
+	return intervals.iter().map( | ( s, e ) | Interval::new( *s, *e ) ).collect();
+}
+
+fn pairs( set: &IntervalSet ) -> Vec< ( i64, i64 ) >
+{
+	return set.intervals().iter().map( | i | ( i.start, i.end ) ).collect();
+}
+
+#[test]
+fn interval()
+{
+	let a = Interval::new( 2, 8 );
+	let b = Interval::new( 3, 7 );
+	let c = Interval::new( 6, 10 );
+	let d = Interval::new( 9, 9 );
+
+	assert_eq!( a.len(), 7 );
+	assert_eq!( d.len(), 1 );
+	assert!( a.contains( 2 ) && a.contains( 8 ) && !a.contains( 9 ) );
+
+	assert!( a.contains_interval( &b ) );
+	assert!( !b.contains_interval( &a ) );
+	assert!( !a.contains_interval( &c ) );
+
+	assert!( a.overlaps( &c ) && c.overlaps( &a ) );
+	assert!( !a.overlaps( &d ) );
+
+	assert_eq!( a.intersection( &c ), Some( Interval::new( 6, 8 ) ) );
+	assert_eq!( a.intersection( &d ), None );
+}
+
+#[test]
+#[should_panic]
+fn backwards_interval()
+{
+	Interval::new( 5, 4 );
+}
+
+#[test]
+fn insert_merges()
+{
+	// a single interval has to survive on its own
+	assert_eq!( pairs( &set( &[ ( 3, 5 ) ] ) ), vec![ ( 3, 5 ) ] );
+
+	// overlapping, touching and contained intervals all merge
+	assert_eq!( pairs( &set( &[ ( 1, 3 ), ( 4, 6 ) ] ) ), vec![ ( 1, 6 ) ] );
+	assert_eq!( pairs( &set( &[ ( 1, 10 ), ( 4, 6 ) ] ) ), vec![ ( 1, 10 ) ] );
+	assert_eq!( pairs( &set( &[ ( 20, 25 ), ( 1, 3 ), ( 10, 12 ), ( 2, 11 ) ] ) ), vec![ ( 1, 12 ), ( 20, 25 ) ] );
+
+	// gaps of one stay apart
+	assert_eq!( pairs( &set( &[ ( 1, 3 ), ( 5, 6 ) ] ) ), vec![ ( 1, 3 ), ( 5, 6 ) ] );
+}
+
+#[test]
+fn membership_and_length()
+{
+	let s = set( &[ ( 1, 3 ), ( 5, 6 ), ( 10, 10 ) ] );
+	assert_eq!( s.len(), 6 );
+	assert!( !s.is_empty() );
+	assert!( IntervalSet::new().is_empty() );
+
+	assert!( s.contains( 5 ) && s.contains( 10 ) );
+	assert!( !s.contains( 4 ) && !s.contains( 11 ) && !s.contains( 0 ) );
+
+	assert!( s.contains_interval( &Interval::new( 1, 3 ) ) );
+	assert!( !s.contains_interval( &Interval::new( 3, 5 ) ) );
+
+	assert!( s.overlaps( &Interval::new( 7, 12 ) ) );
+	assert!( !s.overlaps( &Interval::new( 7, 9 ) ) );
+}
+
+#[test]
+fn set_operations()
+{
+	let a = set( &[ ( 1, 5 ), ( 10, 15 ) ] );
+	let b = set( &[ ( 4, 11 ), ( 14, 20 ) ] );
+
+	assert_eq!( pairs( &a.union( &b ) ), vec![ ( 1, 20 ) ] );
+	assert_eq!( pairs( &a.intersection( &b ) ), vec![ ( 4, 5 ), ( 10, 11 ), ( 14, 15 ) ] );
+	assert_eq!( pairs( &a.difference( &b ) ), vec![ ( 1, 3 ), ( 12, 13 ) ] );
+	assert_eq!( pairs( &b.difference( &a ) ), vec![ ( 6, 9 ), ( 16, 20 ) ] );
+
+	assert!( a.intersection( &IntervalSet::new() ).is_empty() );
+	assert_eq!( a.difference( &IntervalSet::new() ), a );
+	assert!( a.difference( &a ).is_empty() );
+}
+
+#[test]
+fn gaps()
+{
+	let s = set( &[ ( 1, 3 ), ( 5, 6 ), ( 10, 10 ) ] );
+	assert_eq!( s.gaps(), vec![ Interval::new( 4, 4 ), Interval::new( 7, 9 ) ] );
+	assert_eq!( set( &[ ( 1, 3 ) ] ).gaps(), vec![] );
+}
+
+#[test]
+fn coverage()
+{
+	let intervals = [ Interval::new( 2, 4 ), Interval::new( 6, 8 ), Interval::new( 3, 7 ), Interval::new( 10, 10 ) ];
+	let runs: Vec< ( i64, i64, usize ) > = aoc_common::coverage( &intervals ).iter().map( | ( i, d ) | ( i.start, i.end, *d ) ).collect();
+	assert_eq!( runs, vec![ ( 2, 2, 1 ), ( 3, 4, 2 ), ( 5, 5, 1 ), ( 6, 7, 2 ), ( 8, 8, 1 ), ( 10, 10, 1 ) ] );
+
+	// one ending right where the next starts doesn't split the run
+	let runs = aoc_common::coverage( &[ Interval::new( 1, 3 ), Interval::new( 4, 6 ) ] );
+	assert_eq!( runs, vec![ ( Interval::new( 1, 6 ), 1 ) ] );
+
+	assert_eq!( aoc_common::coverage( &[] ), vec![] );
+}
@@ -0,0 +1,206 @@
+// The shared graph searches, over small graphs where the answers are easy
+// to check by hand.
+
+use aoc_common::Grid;
+use aoc_common::search;
+use aoc_common::search::SearchSpace;
+
+// A -> B -> D is two steps but costs 10, A -> C -> E -> D is three steps
+// but only costs 3. F can't be reached.
+struct Roads;
+
+impl SearchSpace for Roads
+{
+	type State = char;
+
+	fn successors( self: &Self, state: &char ) -> Vec< ( char, usize ) >
+	{
+		return match state
+		{
+			'A' => vec![ ( 'B', 5 ), ( 'C', 1 ) ],
+			'B' => vec![ ( 'D', 5 ) ],
+			'C' => vec![ ( 'E', 1 ) ],
+			'E' => vec![ ( 'D', 1 ) ],
+			_ => vec![],
+		};
+	}
+
+	fn is_goal( self: &Self, state: &char ) -> bool
+	{
+		return *state == 'D';
+	}
+}
+
+// Open squares in a map, looking for the bottom right corner
+struct Maze
+{
+	grid: Grid< char >,
+}
+
+impl Maze
+{
+	fn new( map: &str ) -> Maze
+	{
+		return Maze { grid: Grid::parse( map, "`.` or `#`", | _x, _y, c | Some( c ) ).unwrap() };
+	}
+
+	fn goal( self: &Self ) -> ( usize, usize )
+	{
+		return ( self.grid.width() - 1, self.grid.height() - 1 );
+	}
+}
+
+impl SearchSpace for Maze
+{
+	type State = ( usize, usize );
+
+	fn successors( self: &Self, &( x, y ): &( usize, usize ) ) -> Vec< ( ( usize, usize ), usize ) >
+	{
+		return self.grid.neighbors4( x, y )
+			.filter( | n | self.grid[ *n ] == '.' )
+			.map( | n | ( n, 1 ) )
+			.collect();
+	}
+
+	fn is_goal( self: &Self, pos: &( usize, usize ) ) -> bool
+	{
+		return *pos == self.goal();
+	}
+
+	fn heuristic( self: &Self, &( x, y ): &( usize, usize ) ) -> usize
+	{
+		let ( gx, gy ) = self.goal();
+		return gx.abs_diff( x ) + gy.abs_diff( y );
+	}
+}
+
+// S -> B -> C -> G is cheapest, but the heuristic makes B look far from
+// the goal, so C is first reached the expensive way round through A. The
+// heuristic never overestimates, but isn't consistent.
+struct Detour;
+
+impl SearchSpace for Detour
+{
+	type State = char;
+
+	fn successors( self: &Self, state: &char ) -> Vec< ( char, usize ) >
+	{
+		return match state
+		{
+			'S' => vec![ ( 'A', 1 ), ( 'B', 1 ) ],
+			'A' => vec![ ( 'C', 3 ) ],
+			'B' => vec![ ( 'C', 1 ) ],
+			'C' => vec![ ( 'G', 3 ) ],
+			_ => vec![],
+		};
+	}
+
+	fn is_goal( self: &Self, state: &char ) -> bool
+	{
+		return *state == 'G';
+	}
+
+	fn heuristic( self: &Self, state: &char ) -> usize
+	{
+		return if *state == 'B' { 4 } else { 0 };
+	}
+}
+
+// Zig-zags back and forth, so the way to the goal is twice as long as
+// the heuristic guesses
+const MAZE: &str = "\
+.....
+####.
+.....
+.####
+.....
+";
+
+#[test]
+fn bfs_counts_steps()
+{
+	let result = search::bfs( &Roads, 'A' );
+	let path = result.path.unwrap();
+	assert_eq!( path.states, vec![ 'A', 'B', 'D' ] );
+	assert_eq!( path.cost, 2 );
+}
+
+#[test]
+fn dijkstra_counts_costs()
+{
+	let result = search::dijkstra( &Roads, 'A' );
+	let path = result.path.unwrap();
+	assert_eq!( path.states, vec![ 'A', 'C', 'E', 'D' ] );
+	assert_eq!( path.cost, 3 );
+	assert_eq!( result.expanded, 3 );
+}
+
+#[test]
+fn start_can_be_the_goal()
+{
+	let result = search::dijkstra( &Roads, 'D' );
+	assert_eq!( result.path.unwrap().states, vec![ 'D' ] );
+	assert_eq!( result.expanded, 0 );
+}
+
+#[test]
+fn no_path()
+{
+	let result = search::bfs( &Roads, 'F' );
+	assert_eq!( result.path, None );
+	assert_eq!( result.expanded, 1 );
+
+	let result = search::astar( &Roads, 'F' );
+	assert_eq!( result.path, None );
+}
+
+#[test]
+fn maze()
+{
+	let maze = Maze::new( MAZE );
+
+	let bfs = search::bfs( &maze, ( 0, 0 ) );
+	let dijkstra = search::dijkstra( &maze, ( 0, 0 ) );
+	let astar = search::astar( &maze, ( 0, 0 ) );
+	let best_first = search::best_first( &maze, ( 0, 0 ) );
+
+	let path = bfs.path.unwrap();
+	assert_eq!( path.cost, 16 );
+	assert_eq!( path.states.len(), 17 );
+	assert_eq!( path.states.first(), Some( &( 0, 0 ) ) );
+	assert_eq!( path.states.last(), Some( &( 4, 4 ) ) );
+
+	// every step of the path is to a neighboring open square
+	for pair in path.states.windows( 2 )
+	{
+		assert!( maze.successors( &pair[ 0 ] ).contains( &( pair[ 1 ], 1 ) ) );
+	}
+
+	assert_eq!( dijkstra.path.unwrap().cost, 16 );
+	assert_eq!( astar.path.unwrap().cost, 16 );
+	assert!( best_first.path.unwrap().cost >= 16 );
+
+	// a heuristic that never overestimates can only save A* work
+	assert!( astar.expanded <= dijkstra.expanded );
+}
+
+#[test]
+fn astar_reopens_states()
+{
+	let result = search::astar( &Detour, 'S' );
+	let path = result.path.unwrap();
+	assert_eq!( path.states, vec![ 'S', 'B', 'C', 'G' ] );
+	assert_eq!( path.cost, 5 );
+}
+
+#[test]
+fn flood()
+{
+	let reached = search::flood( &Roads, 'B' );
+	assert_eq!( reached.len(), 2 );
+	assert!( reached.contains( &'B' ) && reached.contains( &'D' ) );
+
+	let maze = Maze::new( MAZE );
+	let open = maze.grid.iter().filter( | c | **c == '.' ).count();
+	assert_eq!( search::flood( &maze, ( 0, 0 ) ).len(), open );
+}